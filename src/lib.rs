@@ -0,0 +1,3 @@
+pub mod runner;
+pub mod utils;
+pub mod years;
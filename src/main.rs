@@ -1,56 +1,10 @@
-use std::{env, time::Instant};
+use std::{env, process};
 
-mod days;
-use crate::days::*;
+use aoc2023_rust::runner;
 
 fn main() {
-    let day = env::args()
-        .collect::<Vec<String>>()
-        .get(1)
-        .expect("Expects at least one arguments corresponding to day!")
-        .trim()
-        .to_string()
-        .parse::<u8>()
-        .expect("Invalid day number!");
-
-    let func: fn() = match day {
-        1 => day01::solve,
-        2 => day02::solve,
-        3 => day03::solve,
-        4 => day04::solve,
-        5 => day05::solve,
-        6 => day06::solve,
-        7 => day07::solve,
-        8 => day08::solve,
-        9 => day09::solve,
-        10 => day10::solve,
-        11 => day11::solve,
-        12 => day12::solve,
-        13 => day13::solve,
-        14 => day14::solve,
-        15 => day15::solve,
-        16 => day16::solve,
-        17 => day17::solve,
-        18 => day18::solve,
-        19 => day19::solve,
-        20 => day20::solve,
-        21 => day21::solve,
-        _ => panic!("Day should be in range (1,25) or Day {} is not implemented yet!", day),
-    };
-
-    println!("==================== DAY - {} ====================", day);
-
-    let time = Instant::now();
-    func();
-    let mut elapsed_time = time.elapsed().as_nanos() as f64 / 1000.0;
-    let mut time_unit = String::from("µs");
-    if elapsed_time > 1000.0 {
-        elapsed_time /= 1000.0;
-        time_unit = String::from("ms");
-    }
-    if elapsed_time > 1000.0 {
-        elapsed_time /= 1000.0;
-        time_unit = String::from("sec");
+    if let Err(message) = runner::run(env::args().skip(1)) {
+        eprintln!("{}", message);
+        process::exit(1);
     }
-    println!("  · Elapsed: {:.2} {}", elapsed_time, time_unit);
 }
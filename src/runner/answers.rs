@@ -0,0 +1,58 @@
+use std::{collections::BTreeMap, fs, time::Duration};
+
+use crate::years;
+
+// Answers and timings of the last runs on the puzzle inputs of the year, one `day part answer
+// microseconds` line (tab separated) per part
+pub fn path(year: u16) -> String {
+    format!("{}/answers.txt", years::inputs_dir(year))
+}
+
+pub type Answers = BTreeMap<(u8, usize), (String, Duration)>;
+
+// Missing files and broken lines are just not recorded
+pub fn load(path: &str) -> Answers {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| match line.split('\t').collect::<Vec<&str>>().as_slice() {
+            [day, part, answer, micros] => Some(((day.parse().ok()?, part.parse().ok()?),
+                                                 (answer.to_string(), Duration::from_micros(micros.parse().ok()?)))),
+            _ => None,
+        })
+        .collect()
+}
+
+// Replaces the answers of the day
+pub fn record(path: &str, day: u8, results: &[(String, Duration)]) -> Result<(), String> {
+    let mut answers = load(path);
+    answers.retain(|(d, _), _| *d != day);
+    for (part, (answer, elapsed)) in results.iter().enumerate() {
+        answers.insert((day, part + 1), (answer.clone(), *elapsed));
+    }
+    let content = answers.iter()
+        .map(|((day, part), (answer, elapsed))| format!("{}\t{}\t{}\t{}\n", day, part, answer, elapsed.as_micros()))
+        .collect::<String>();
+    fs::write(path, content).map_err(|e| format!("Cannot write {}: {}", path, e))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc2023_answers_{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        assert!(load(path).is_empty());
+        record(path, 9, &[("114".to_string(), Duration::from_micros(25)), ("2".to_string(), Duration::from_micros(7))]).unwrap();
+        record(path, 1, &[("142".to_string(), Duration::from_micros(3))]).unwrap();
+        record(path, 9, &[("18".to_string(), Duration::from_micros(30))]).unwrap();
+        let answers = load(path);
+        fs::remove_file(path).unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[&(1, 1)], ("142".to_string(), Duration::from_micros(3)));
+        assert_eq!(answers[&(9, 1)], ("18".to_string(), Duration::from_micros(30)));
+    }
+}
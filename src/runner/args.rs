@@ -0,0 +1,90 @@
+use std::{collections::HashMap, str::FromStr};
+
+// Options followed by a value, every other `--name` is a flag
+const VALUE_OPTIONS: &[&str] = &["size", "seed", "input", "part", "sizes", "budget", "frame-delay", "out", "format", "zoom", "port", "fixtures", "interval", "threads", "year", "config", "timeout", "output"];
+
+#[derive(Debug, Default)]
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    // `--name value`, `--name=value` and `--flag` can go anywhere between the positional arguments
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(option) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg);
+                continue;
+            };
+            let (name, value) = match option.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None if VALUE_OPTIONS.contains(&option) => {
+                    let value = args.next().ok_or(format!("Option --{} expects a value!", option))?;
+                    (option.to_string(), Some(value))
+                },
+                None => (option.to_string(), None),
+            };
+            parsed.options.insert(name, value);
+        }
+        Ok(parsed)
+    }
+
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(|s| s.as_str())
+    }
+
+    pub fn day(&self, index: usize) -> Result<u8, String> {
+        self.positional(index)
+            .ok_or("Expects at least one arguments corresponding to day!")?
+            .trim()
+            .parse::<u8>()
+            .map_err(|_| "Invalid day number!".to_string())
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.options.get(name) {
+            Some(Some(value)) => value.parse::<T>()
+                .map(Some)
+                .map_err(|_| format!("Invalid value `{}` of --{}!", value, name)),
+            Some(None) => Err(format!("Option --{} expects a value!", name)),
+            None => Ok(None),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<Args, String> {
+        Args::parse(line.split_whitespace().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn parsing() {
+        let parsed = args("generate 17 --size 100 --seed=7 --fast").unwrap();
+        assert_eq!(parsed.positional(0), Some("generate"));
+        assert_eq!(parsed.day(1), Ok(17));
+        assert_eq!(parsed.value::<usize>("size"), Ok(Some(100)));
+        assert_eq!(parsed.value::<u64>("seed"), Ok(Some(7)));
+        assert_eq!(parsed.value::<u64>("other"), Ok(None));
+        assert!(parsed.flag("fast"));
+        assert!(parsed.value::<u64>("fast").is_err());
+    }
+
+    #[test]
+    fn errors() {
+        assert!(args("generate 1 --size").is_err());
+        assert!(args("generate 1 --size x").unwrap().value::<usize>("size").is_err());
+        assert!(args("x").unwrap().day(0).is_err());
+        assert!(args("").unwrap().day(0).is_err());
+    }
+}
@@ -0,0 +1,31 @@
+use std::{fs, io::BufWriter, path::Path};
+
+use crate::{runner::{self, Args}, utils::image::Format};
+
+// `export <day> [--input file] [--out dir] [--format ppm|pgm] [--zoom k]` writes the images of the
+// final grid states as <dir>/day<NN>_<name>.<format>, every cell drawn as k x k pixels
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = args.day(1)?;
+    let days = runner::calendar(args)?;
+    let export = days.exporter(day).ok_or(format!("Day {} has no images to export!", day))?;
+    let format = match runner::setting::<String>(args, "format")?.as_deref() {
+        None | Some("ppm") => Format::Ppm,
+        Some("pgm") => Format::Pgm,
+        Some(other) => return Err(format!("Unknown image format `{}`, expected ppm or pgm!", other)),
+    };
+    let zoom = args.value::<usize>("zoom")?.unwrap_or(4);
+    let out = args.value::<String>("out")?.unwrap_or("./images".to_string());
+
+    let input = runner::read_input(args, day)?;
+    let images = export(&input).map_err(|e| format!("Invalid input, {}", e))?;
+    fs::create_dir_all(&out).map_err(|e| format!("Cannot create {}: {}", out, e))?;
+    for (name, image) in images {
+        let path = Path::new(&out).join(format!("day{:02}_{}.{}", day, name, format.extension()));
+        let file = fs::File::create(&path).map_err(|e| format!("Cannot create {}: {}", path.display(), e))?;
+        image.scaled(zoom)
+            .write(format, &mut BufWriter::new(file))
+            .map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
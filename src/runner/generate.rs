@@ -0,0 +1,16 @@
+use std::io::{self, Write};
+
+use crate::{runner::{self, Args}, utils::rng::Rng};
+
+// `generate <day> --size N --seed S` prints a random input of the day
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = args.day(1)?;
+    let days = runner::calendar(args)?;
+    let generate = days.generator(day)
+        .ok_or(format!("Day should be in range (1,25) or Day {} is not implemented yet!", day))?;
+    let size = args.value::<usize>("size")?.unwrap_or(10);
+    let seed = args.value::<u64>("seed")?.unwrap_or(0);
+
+    let input = generate(&mut Rng::new(seed), size);
+    io::stdout().write_all(input.as_bytes()).map_err(|e| e.to_string())
+}
@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use crate::{runner::{self, answers, Args}, years};
+
+// `list [--year Y]` prints every day of the calendar with its title, the implemented parts, whether
+// its puzzle input is there and the answers of its last run
+pub fn run(args: &Args) -> Result<(), String> {
+    let year = runner::year(args)?;
+    let days = runner::calendar(args)?;
+    let recorded = answers::load(&answers::path(year));
+    println!("{:>3}  {:<32} {:<7} {:<7} {:<6} Last answers", "Day", "Title", "Part 1", "Part 2", "Input");
+    for day in 1..=25 {
+        let parts = days.parts(day).len();
+        let implemented = |part: usize| if part <= parts { "yes" } else { "-" };
+        let input = Path::new(&format!("{}/input_{}.txt", years::inputs_dir(year), day)).exists();
+        let last = (1..=parts)
+            .filter_map(|part| recorded.get(&(day, part)))
+            .map(|(answer, elapsed)| format!("{} ({})", answer, runner::format_duration(*elapsed)))
+            .collect::<Vec<String>>();
+        let line = format!("{:>3}  {:<32} {:<7} {:<7} {:<6} {}", day, days.title(day).unwrap_or("?"),
+                           implemented(1), implemented(2), if input { "yes" } else { "-" }, last.join(", "));
+        println!("{}", line.trim_end());
+    }
+    Ok(())
+}
@@ -0,0 +1,251 @@
+// Command line front end: `<day>` runs the solution of the day (`--threads N` spreads the independent
+// items of days 5, 12, 16 and 19 over N threads, `--visualize` draws the grids of the days that
+// support it, animated with `--frame-delay ms`, `--explain` traces what every item adds to the
+// answers, `--dot` prints the graph of the day instead, `--watch` solves it again whenever its input
+// changes), the other commands but `list` and `serve` take the day as their first argument. `--year Y`
+// picks the calendar, 2023 by default. `--timeout ms` gives up on a part running longer than that,
+// a part that panics or overflows is reported with its day and part.
+// Inputs lose their BOM, CRLF endings, trailing spaces and blank lines, `--strict` rejects them instead.
+// Defaults of the options and the parameters of the days are read from `--config file`, ./aoc.toml
+// when there is one. `--output json` prints the answers as one JSON object per part.
+
+mod answers;
+mod args;
+mod export;
+mod generate;
+mod list;
+mod scale;
+mod serve;
+mod validate;
+mod watch;
+
+use std::{
+    fmt,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    str::FromStr,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+pub use args::Args;
+
+use crate::{
+    utils::{checked::Overflow, config::{self, Config, Kind, Value}, explain, parallel, parse::{self, ParseError}, visualize},
+    years::{self, Calendar, Part},
+};
+
+const CONFIG: &str = "./aoc.toml";
+// The options that aoc.toml can give a default, the days declare their own parameters
+const SETTINGS: &[(&str, Kind)] = &[
+    ("inputs", Kind::Str),
+    ("format", Kind::OneOf(&["ppm", "pgm"])),
+    ("output", Kind::OneOf(&["text", "json"])),
+    ("threads", Kind::Count),
+    ("timeout", Kind::Count),
+];
+
+pub fn run(args: impl IntoIterator<Item = String>) -> Result<(), String> {
+    let args = Args::parse(args)?;
+    load_config(&args)?;
+    if args.flag("visualize") {
+        visualize::enable(args.value::<u64>("frame-delay")?.map(Duration::from_millis));
+    }
+    if args.flag("explain") {
+        explain::enable();
+    }
+    if let Some(threads) = setting::<usize>(&args, "threads")? {
+        parallel::set_threads(threads);
+    }
+    match args.positional(0) {
+        Some("list") => list::run(&args),
+        Some("generate") => generate::run(&args),
+        Some("validate") => validate::run(&args),
+        Some("scale") => scale::run(&args),
+        Some("export") => export::run(&args),
+        Some("serve") => serve::run(&args),
+        _ if args.flag("dot") => dot(&args),
+        _ if args.flag("watch") => watch::run(&args),
+        _ => solve(&args),
+    }
+}
+
+// Runs the implemented parts, the answers on the puzzle input are recorded for `list`
+fn solve(args: &Args) -> Result<(), String> {
+    let day = args.day(0)?;
+    let parts = calendar(args)?.parts(day);
+    if parts.is_empty() {
+        return Err(format!("Day should be in range (1,25) or Day {} is not implemented yet!", day));
+    }
+    let json = match setting::<String>(args, "output")?.as_deref() {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(other) => return Err(format!("Unknown output `{}`, expected text or json!", other)),
+    };
+    let input = read_input(args, day)?;
+    let timeout = setting::<u64>(args, "timeout")?.map(Duration::from_millis);
+
+    if !json {
+        println!("==================== DAY - {} ====================", day);
+    }
+
+    let time = Instant::now();
+    let mut results = vec![];
+    for (i, part) in parts.iter().enumerate() {
+        let start = Instant::now();
+        let answer = solve_part(day, i + 1, *part, &input, timeout).map_err(|f| f.to_string())?;
+        let elapsed = start.elapsed();
+        if json {
+            println!("{}", serve::answer_json(day, i + 1, &answer, elapsed));
+        } else {
+            println!("Part {} solution = {}", i + 1, answer);
+        }
+        results.push((answer, elapsed));
+    }
+    if !json {
+        println!("  · Elapsed: {}", format_duration(time.elapsed()));
+    }
+    if args.value::<String>("input")?.is_none() {
+        answers::record(&answers::path(year(args)?), day, &results)?;
+    }
+    Ok(())
+}
+
+// Why a part has no answer. Panics are reported with their day and part, the checked answer
+// arithmetic with what overflowed.
+#[derive(Debug)]
+enum Failure {
+    Invalid(ParseError),
+    Overflowed { day: u8, part: usize, what: String },
+    Panicked { day: u8, part: usize, message: String },
+    TimedOut { day: u8, part: usize, after: Duration },
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Invalid(e) => write!(f, "Invalid input, {}", e),
+            Failure::Overflowed { day, part, what } => write!(f, "Day {} part {} overflowed: {}", day, part, what),
+            Failure::Panicked { day, part, message } => write!(f, "Day {} part {} panicked: {}", day, part, message),
+            Failure::TimedOut { day, part, after } => write!(f, "Day {} part {} timed out after {}", day, part, format_duration(*after)),
+        }
+    }
+}
+
+fn run_part(day: u8, number: usize, part: Part, input: &str) -> Result<String, Failure> {
+    match panic::catch_unwind(AssertUnwindSafe(|| part(input))) {
+        Ok(answer) => answer.map_err(Failure::Invalid),
+        Err(payload) => match payload.downcast::<Overflow>() {
+            Ok(overflow) => Err(Failure::Overflowed { day, part: number, what: overflow.0 }),
+            Err(payload) => {
+                let message = payload.downcast_ref::<&str>().map(|m| m.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Err(Failure::Panicked { day, part: number, message })
+            },
+        },
+    }
+}
+
+// With a timeout the part runs on its own thread, left behind when it does not answer in time
+fn solve_part(day: u8, number: usize, part: Part, input: &str, timeout: Option<Duration>) -> Result<String, Failure> {
+    let Some(timeout) = timeout else {
+        return run_part(day, number, part, input);
+    };
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    thread::spawn(move || {
+        let _ = sender.send(run_part(day, number, part, &input));
+    });
+    receiver.recv_timeout(timeout)
+        .unwrap_or(Err(Failure::TimedOut { day, part: number, after: timeout }))
+}
+
+// The parsed structure of the day as Graphviz DOT, `--input file` reads another input
+fn dot(args: &Args) -> Result<(), String> {
+    let day = args.day(0)?;
+    let graph = calendar(args)?.grapher(day).ok_or(format!("Day {} is not a graph puzzle!", day))?;
+    let input = read_input(args, day)?;
+    print!("{}", graph(&input).map_err(|e| format!("Invalid input, {}", e))?);
+    Ok(())
+}
+
+fn format_duration(duration: Duration) -> String {
+    let mut elapsed_time = duration.as_nanos() as f64 / 1000.0;
+    let mut time_unit = String::from("µs");
+    if elapsed_time > 1000.0 {
+        elapsed_time /= 1000.0;
+        time_unit = String::from("ms");
+    }
+    if elapsed_time > 1000.0 {
+        elapsed_time /= 1000.0;
+        time_unit = String::from("sec");
+    }
+    format!("{:.2} {}", elapsed_time, time_unit)
+}
+
+// --config file, ./aoc.toml when it exists, nothing is configured otherwise. Unknown keys and values
+// of the wrong kind are refused before anything runs.
+fn load_config(args: &Args) -> Result<(), String> {
+    let path = match args.value::<String>("config")? {
+        Some(path) => path,
+        None if Path::new(CONFIG).exists() => CONFIG.to_string(),
+        None => return Ok(()),
+    };
+    let config = Config::parse(&read_file(&path)?).map_err(|e| format!("Invalid {}, {}", path, e))?;
+    config.check(|key| SETTINGS.iter().find(|(name, _)| *name == key).map(|(_, kind)| *kind).or_else(|| years::param_kind(key)))
+        .map_err(|e| format!("Invalid {}, {}", path, e))?;
+    config::set(config);
+    Ok(())
+}
+
+// --name, the `name` key of the configuration otherwise
+fn setting<T: FromStr>(args: &Args, name: &str) -> Result<Option<T>, String> {
+    if let Some(value) = args.value::<T>(name)? {
+        return Ok(Some(value));
+    }
+    let text = match config::get(name) {
+        None => return Ok(None),
+        Some(Value::Str(text)) => text.clone(),
+        Some(Value::Int(value)) => value.to_string(),
+        Some(Value::Float(value)) => value.to_string(),
+        Some(Value::Bool(value)) => value.to_string(),
+        Some(Value::Array(_)) => String::new(),
+    };
+    text.parse().map(Some).map_err(|_| format!("Invalid value for `{}` in the configuration!", name))
+}
+
+fn year(args: &Args) -> Result<u16, String> {
+    Ok(args.value::<u16>("year")?.unwrap_or(years::DEFAULT_YEAR))
+}
+
+// The days of --year, 2023 by default
+fn calendar(args: &Args) -> Result<&'static dyn Calendar, String> {
+    let year = year(args)?;
+    years::calendar(year).ok_or(format!("Year {} is not implemented yet!", year))
+}
+
+// The input of the day given by --input, the puzzle input of the year by default
+fn input_path(args: &Args, day: u8) -> Result<String, String> {
+    let default = format!("{}/input_{}.txt", years::inputs_dir(year(args)?), day);
+    Ok(args.value::<String>("input")?.unwrap_or(default))
+}
+
+fn read_input(args: &Args, day: u8) -> Result<String, String> {
+    load_input(args, &input_path(args, day)?)
+}
+
+// The normalized input, `--strict` refuses an input that needed fixing and says what was wrong
+fn load_input(args: &Args, path: &str) -> Result<String, String> {
+    let (input, fixes) = parse::normalize(&read_file(path)?);
+    if args.flag("strict") && !fixes.is_empty() {
+        return Err(format!("{} needs normalizing: {}", path, fixes.join(", ")));
+    }
+    Ok(input)
+}
+
+fn read_file(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path, e))
+}
@@ -0,0 +1,77 @@
+use std::{io::{self, Write}, time::{Duration, Instant}};
+
+use crate::{runner::{self, Args}, utils::{complexity, rng::Rng}};
+
+const DEFAULT_SIZES: &[usize] = &[4, 8, 16, 32, 64, 128, 256, 512, 1024];
+
+// `scale <day> [--part P] [--sizes 10,20,40] [--seed S] [--budget ms]` times the parts on generated
+// inputs of growing size and fits the times to complexity classes of the input length. The sizes
+// stop growing once a run takes longer than the budget (default 1000 ms).
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = args.day(1)?;
+    let days = runner::calendar(args)?;
+    let generate = days.generator(day)
+        .ok_or(format!("Day should be in range (1,25) or Day {} is not implemented yet!", day))?;
+    let mut parts = days.parts(day).into_iter().enumerate().collect::<Vec<_>>();
+    if let Some(part) = args.value::<usize>("part")? {
+        parts.retain(|(i, _)| i + 1 == part);
+        if parts.is_empty() {
+            return Err(format!("Part {} of day {} is not implemented!", part, day));
+        }
+    }
+    let sizes = match args.value::<String>("sizes")? {
+        Some(sizes) => sizes.split(',')
+            .map(|s| s.trim().parse::<usize>().map_err(|_| format!("Invalid size `{}`!", s)))
+            .collect::<Result<Vec<usize>, String>>()?,
+        None => DEFAULT_SIZES.to_vec(),
+    };
+    let seed = args.value::<u64>("seed")?.unwrap_or(0);
+    let budget = Duration::from_millis(args.value::<u64>("budget")?.unwrap_or(1000));
+
+    println!("==================== DAY - {} SCALING ====================", day);
+    print!("{:>8} {:>10}", "size", "bytes");
+    parts.iter().for_each(|(i, _)| print!(" {:>14}", format!("part {}", i + 1)));
+    println!();
+
+    // (input length, seconds) for every part
+    let mut samples = vec![vec![]; parts.len()];
+    for size in sizes {
+        let input = generate(&mut Rng::new(seed), size);
+        print!("{:>8} {:>10}", size, input.len());
+        io::stdout().flush().map_err(|e| e.to_string())?;
+        let mut over_budget = false;
+        for ((i, part), samples) in parts.iter().zip(samples.iter_mut()) {
+            // best of 3 runs, slow runs are not repeated
+            let mut best = Duration::MAX;
+            for _ in 0..3 {
+                let time = Instant::now();
+                part(&input).map_err(|e| format!("\nPart {} rejected the generated input, {}", i + 1, e))?;
+                best = best.min(time.elapsed());
+                if best > budget / 10 {
+                    break;
+                }
+            }
+            print!(" {:>14}", runner::format_duration(best));
+            io::stdout().flush().map_err(|e| e.to_string())?;
+            samples.push((input.len() as f64, best.as_secs_f64()));
+            over_budget |= best > budget;
+        }
+        println!();
+        if over_budget {
+            break;
+        }
+    }
+
+    for ((i, _), samples) in parts.iter().zip(samples.iter_mut()) {
+        // sizes the generator clamps give the same input again
+        samples.dedup_by(|a, b| a.0 == b.0);
+        if samples.len() < 3 || samples[samples.len() - 1].0 < 4.0 * samples[0].0 {
+            println!("  · Part {}: the input does not grow enough to fit", i + 1);
+            continue;
+        }
+        let fits = complexity::fit(samples);
+        println!("  · Part {}: time ~ n^{:.2} of the input length, best fit {} (error {:.0}%), then {} ({:.0}%)",
+                 i + 1, complexity::exponent(samples), fits[0].0, 100.0 * fits[0].1, fits[1].0, 100.0 * fits[1].1);
+    }
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{atomic::{AtomicUsize, Ordering}, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{runner::{self, Args, Failure}, utils::parse, years};
+
+const MAX_BODY: usize = 16 << 20;
+// The request line and the headers together
+const MAX_HEAD: u64 = 16 << 10;
+// A client that stops sending its request in the middle does not keep its thread
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+// Connections beyond that are turned away instead of getting a thread
+const MAX_CONNECTIONS: usize = 64;
+
+// `serve [--port N]` answers `POST /day/{n}/part/{p}` requests carrying the puzzle input as their
+// body with `{"day": n, "part": p, "answer": "...", "elapsed_us": t}`, errors are `{"error": "..."}`.
+// `/{year}/day/{n}/part/{p}` picks another year than 2023. `--timeout ms` bounds every solve.
+pub fn run(args: &Args) -> Result<(), String> {
+    let port = args.value::<u16>("port")?.unwrap_or(8023);
+    let timeout = runner::setting::<u64>(args, "timeout")?.map(Duration::from_millis);
+    let listener = TcpListener::bind(("127.0.0.1", port)).map_err(|e| format!("Cannot listen on port {}: {}", port, e))?;
+    println!("Serving the solvers on http://127.0.0.1:{}/day/{{n}}/part/{{p}}", port);
+
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        match stream {
+            Ok(mut stream) => {
+                let Some(slot) = Slot::take(&active) else {
+                    write_response(&mut stream, 503, &error("too many connections, try again later"));
+                    continue;
+                };
+                thread::spawn(move || {
+                    handle(stream, timeout);
+                    drop(slot);
+                });
+            }
+            Err(e) => eprintln!("Connection failed: {}", e),
+        }
+    }
+    Ok(())
+}
+
+// One of the MAX_CONNECTIONS connections, given back when dropped (even by a panicking handler)
+struct Slot(Arc<AtomicUsize>);
+
+impl Slot {
+    fn take(active: &Arc<AtomicUsize>) -> Option<Slot> {
+        active.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| (n < MAX_CONNECTIONS).then_some(n + 1))
+            .ok()
+            .map(|_| Slot(Arc::clone(active)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn handle(mut stream: TcpStream, timeout: Option<Duration>) {
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    let (status, body) = match read_request(&stream) {
+        Ok((method, path, body)) => respond(&method, &path, &body, timeout),
+        Err((status, message)) => (status, error(&message)),
+    };
+    write_response(&mut stream, status, &body);
+}
+
+fn write_response(stream: &mut TcpStream, status: u16, body: &str) {
+    let _ = write!(stream, "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                   status, reason(status), body.len(), body);
+}
+
+// Method, path and body of the request, the body is as long as its Content-Length. A head longer
+// than MAX_HEAD is refused with 431, other malformed requests with 400.
+fn read_request(stream: impl Read) -> Result<(String, String, String), (u16, String)> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    let mut budget = MAX_HEAD;
+    read_head_line(&mut reader, &mut line, &mut budget)?;
+    let mut request_line = line.split_whitespace();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        return Err((400, "malformed request line".to_string()));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut length = 0;
+    loop {
+        read_head_line(&mut reader, &mut line, &mut budget)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse::<usize>().map_err(|_| (400, "invalid Content-Length".to_string()))?;
+            }
+        }
+    }
+    if length > MAX_BODY {
+        return Err((400, format!("the input is larger than {} bytes", MAX_BODY)));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(|e| (400, e.to_string()))?;
+    let body = String::from_utf8(body).map_err(|_| (400, "the input is not valid UTF-8".to_string()))?;
+    Ok((method, path, body))
+}
+
+// Reads no more than what is left of the head budget, a line still going when it runs out is refused
+fn read_head_line(reader: &mut impl BufRead, line: &mut String, budget: &mut u64) -> Result<(), (u16, String)> {
+    line.clear();
+    let read = reader.by_ref().take(*budget).read_line(line).map_err(|e| (400, e.to_string()))?;
+    *budget -= read as u64;
+    if *budget == 0 && !line.ends_with('\n') {
+        return Err((431, format!("the request line and headers are larger than {} bytes", MAX_HEAD)));
+    }
+    Ok(())
+}
+
+fn respond(method: &str, path: &str, body: &str, timeout: Option<Duration>) -> (u16, String) {
+    let route = match path.trim_matches('/').split('/').collect::<Vec<&str>>().as_slice() {
+        ["day", day, "part", part] => Some((years::DEFAULT_YEAR, day.parse::<u8>().ok(), part.parse::<usize>().ok())),
+        [year, "day", day, "part", part] => year.parse::<u16>().ok().map(|y| (y, day.parse().ok(), part.parse().ok())),
+        _ => None,
+    };
+    let Some((year, Some(day), Some(part))) = route else {
+        return (404, error("expected /day/{n}/part/{p} or /{year}/day/{n}/part/{p}"));
+    };
+    if method != "POST" {
+        return (405, error("the input has to be POSTed"));
+    }
+    let Some(days) = years::calendar(year) else {
+        return (404, error(&format!("Year {} is not implemented yet!", year)));
+    };
+    let parts = days.parts(day);
+    if parts.is_empty() {
+        return (404, error(&format!("Day should be in range (1,25) or Day {} is not implemented yet!", day)));
+    }
+    let Some(solve) = part.checked_sub(1).and_then(|p| parts.get(p)) else {
+        return (404, error(&format!("Day {} has no part {}", day, part)));
+    };
+
+    let body = parse::normalize(body).0;
+    let time = Instant::now();
+    match runner::solve_part(day, part, *solve, &body, timeout) {
+        Ok(answer) => (200, answer_json(day, part, &answer, time.elapsed())),
+        Err(failure @ Failure::Invalid(_)) => (422, error(&failure.to_string())),
+        Err(failure @ (Failure::Overflowed { .. } | Failure::Panicked { .. })) => (500, error(&failure.to_string())),
+        Err(failure @ Failure::TimedOut { .. }) => (503, error(&failure.to_string())),
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+// Also the `--output json` lines of the runner
+pub(super) fn answer_json(day: u8, part: usize, answer: &str, elapsed: Duration) -> String {
+    format!("{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_us\": {}}}", day, part, quote(answer), elapsed.as_micros())
+}
+
+fn error(message: &str) -> String {
+    format!("{{\"error\": {}}}", quote(message))
+}
+
+fn quote(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            c if (c as u32) < 0x20 => out += &format!("\\u{:04x}", c as u32),
+            c => out.push(c),
+        }
+    }
+    out + "\""
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    #[test]
+    fn responses() {
+        let (status, body) = respond("POST", "/day/9/part/2", "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n", None);
+        assert_eq!(status, 200);
+        assert!(body.starts_with("{\"day\": 9, \"part\": 2, \"answer\": \"2\", \"elapsed_us\": "));
+
+        assert_eq!(respond("POST", "/day/25/part/1", "", None).0, 404);
+        assert_eq!(respond("POST", "/day/21/part/2", "", None).0, 404);
+        assert_eq!(respond("POST", "/days", "", None).0, 404);
+        assert!(respond("POST", "/2023/day/9/part/2", "1 2 3", None).1.starts_with("{\"day\": 9, \"part\": 2, \"answer\": \"0\""));
+        assert_eq!(respond("POST", "/2015/day/1/part/1", "", None).0, 404);
+        assert_eq!(respond("GET", "/day/9/part/1", "", None).0, 405);
+        assert_eq!(respond("POST", "/day/9/part/1", "0 3 x", None), (422, error("Invalid input, line 1, column 5: cannot parse `x`")));
+        assert_eq!(quote("a\"b\\\n\t"), "\"a\\\"b\\\\\\n\\u0009\"");
+    }
+
+    #[test]
+    fn timeouts() {
+        let input = years::y2023::day14::generate(&mut Rng::new(14), 60);
+        assert_eq!(respond("POST", "/day/14/part/2", &input, Some(Duration::ZERO)),
+                   (503, error("Day 14 part 2 timed out after 0.00 µs")));
+    }
+
+    #[test]
+    fn requests() {
+        let request = "POST /day/9/part/1 HTTP/1.1\r\nContent-Length: 5\r\n\r\n1 2 3";
+        assert_eq!(read_request(request.as_bytes()),
+                   Ok(("POST".to_string(), "/day/9/part/1".to_string(), "1 2 3".to_string())));
+        assert_eq!(read_request("GET\r\n\r\n".as_bytes()).unwrap_err().0, 400);
+
+        let endless = "x".repeat(MAX_HEAD as usize + 1);
+        assert_eq!(read_request(endless.as_bytes()).unwrap_err().0, 431);
+        let headers = format!("POST /day/9/part/1 HTTP/1.1\r\n{}\r\n", "X-Padding: 0\r\n".repeat(2000));
+        assert_eq!(read_request(headers.as_bytes()).unwrap_err().0, 431);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{runner::{self, Args}, utils::assumption};
+
+// `validate <day> [--input file]` reports which assumptions of the day hold on the input
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = args.day(1)?;
+    let days = runner::calendar(args)?;
+    if days.parts(day).is_empty() {
+        return Err(format!("Day should be in range (1,25) or Day {} is not implemented yet!", day));
+    }
+    let Some(validate) = days.validator(day) else {
+        println!("Day {} does not assume anything beyond the puzzle description", day);
+        return Ok(());
+    };
+
+    let input = runner::read_input(args, day)?;
+    let assumptions = validate(&input).map_err(|e| format!("Invalid input, {}", e))?;
+    assumptions.iter().for_each(|a| println!("{}", a));
+    if assumption::all_hold(&assumptions) {
+        Ok(())
+    } else {
+        Err(format!("Some assumptions of day {} do not hold, its shortcuts are not safe!", day))
+    }
+}
@@ -0,0 +1,96 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::runner::{self, Args};
+
+// `<day> --watch [--input file] [--fixtures dir] [--interval ms]` polls the modification times of
+// the input and of the files of the fixtures directory, and solves the day again on all of them when
+// one changes. Every answer is compared to the one of the previous run, a part that fails or runs
+// over `--timeout ms` gets the reason instead and does not end the watch.
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = args.day(0)?;
+    let days = runner::calendar(args)?;
+    let parts = days.parts(day);
+    if parts.is_empty() {
+        return Err(format!("Day should be in range (1,25) or Day {} is not implemented yet!", day));
+    }
+    let input = runner::input_path(args, day)?;
+    let fixtures = args.value::<String>("fixtures")?;
+    let interval = Duration::from_millis(args.value::<u64>("interval")?.unwrap_or(500));
+    let timeout = runner::setting::<u64>(args, "timeout")?.map(Duration::from_millis);
+
+    let mut previous = HashMap::<(PathBuf, usize), String>::new();
+    let mut stamps = vec![];
+    let mut run = 0;
+    loop {
+        let files = watched_files(&input, fixtures.as_deref())?;
+        let current = files.iter().map(|f| (f.clone(), modified(f))).collect::<Vec<_>>();
+        if current == stamps {
+            thread::sleep(interval);
+            continue;
+        }
+        stamps = current;
+        run += 1;
+
+        println!("==================== DAY - {} · run {} ====================", day, run);
+        for file in files.iter() {
+            println!("{}", file.display());
+            let answers = match runner::load_input(args, &file.to_string_lossy()) {
+                Ok(input) => parts.iter()
+                    .enumerate()
+                    .map(|(part, solve)| runner::solve_part(day, part + 1, *solve, &input, timeout).unwrap_or_else(|f| f.to_string()))
+                    .collect(),
+                Err(e) => vec![e],
+            };
+            for (part, answer) in answers.into_iter().enumerate() {
+                let key = (file.clone(), part);
+                println!("  Part {} solution = {}", part + 1, diff(previous.get(&key).map(|a| a.as_str()), &answer));
+                previous.insert(key, answer);
+            }
+        }
+    }
+}
+
+fn watched_files(input: &str, fixtures: Option<&str>) -> Result<Vec<PathBuf>, String> {
+    let mut files = vec![PathBuf::from(input)];
+    if let Some(dir) = fixtures {
+        let mut entries = fs::read_dir(dir)
+            .map_err(|e| format!("Cannot read {}: {}", dir, e))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.is_file())
+            .collect::<Vec<PathBuf>>();
+        entries.sort();
+        files.extend(entries);
+    }
+    Ok(files)
+}
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn diff(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => current.to_string(),
+        Some(p) if p == current => format!("{} (unchanged)", current),
+        Some(p) => format!("{} (was {})", current, p),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_diff() {
+        assert_eq!(diff(None, "114"), "114");
+        assert_eq!(diff(Some("114"), "114"), "114 (unchanged)");
+        assert_eq!(diff(Some("110"), "114"), "114 (was 110)");
+    }
+}
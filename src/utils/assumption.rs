@@ -0,0 +1,40 @@
+// Structural properties of an input that a solver shortcut relies on, reported by `validate <day>`
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    pub description: String,
+    pub holds: bool,
+}
+
+impl Assumption {
+    pub fn new(description: impl Into<String>, holds: bool) -> Assumption {
+        Assumption { description: description.into(), holds }
+    }
+}
+
+impl fmt::Display for Assumption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}", if self.holds { " ok " } else { "FAIL" }, self.description)
+    }
+}
+
+pub fn all_hold(assumptions: &[Assumption]) -> bool {
+    assumptions.iter().all(|a| a.holds)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report() {
+        let assumptions = [Assumption::new("S is in the center", true), Assumption::new(format!("{} ends", 2), false)];
+        assert_eq!(assumptions[0].to_string(), "[ ok ] S is in the center");
+        assert_eq!(assumptions[1].to_string(), "[FAIL] 2 ends");
+        assert!(!all_hold(&assumptions));
+        assert!(all_hold(&assumptions[..1]));
+    }
+}
@@ -0,0 +1,82 @@
+// Tiny benchmark harness for the hot inner functions. The benchmarks are ignored tests, run them
+// with `cargo test --release -- --ignored bench` (add --nocapture to see the numbers).
+// BENCH_TIME_MS sets how long every benchmark is measured (default 1000 ms).
+
+use std::{env, hint::black_box, time::{Duration, Instant}};
+
+#[derive(Debug, Clone)]
+pub struct Stats {
+    pub iterations: u64,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+fn measure_time() -> Duration {
+    let ms = env::var("BENCH_TIME_MS").ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(1000);
+    Duration::from_millis(ms)
+}
+
+// Runs batches of calls, the setup of every call (e.g. cloning the state the function mutates) is
+// done before the batch starts and does not count
+fn run<I, T>(time: Duration, mut setup: impl FnMut() -> I, mut f: impl FnMut(I) -> T) -> Stats {
+    // warm up and find a batch size that takes about a millisecond
+    let mut batch = 1_u64;
+    loop {
+        let inputs = (0..batch).map(|_| setup()).collect::<Vec<I>>();
+        let start = Instant::now();
+        inputs.into_iter().for_each(|i| { black_box(f(black_box(i))); });
+        if start.elapsed() >= Duration::from_millis(1) || batch >= 1 << 20 {
+            break;
+        }
+        batch *= 2;
+    }
+
+    let mut samples = vec![];
+    let started = Instant::now();
+    while samples.len() < 10 || (started.elapsed() < time && samples.len() < 10000) {
+        let inputs = (0..batch).map(|_| setup()).collect::<Vec<I>>();
+        let start = Instant::now();
+        inputs.into_iter().for_each(|i| { black_box(f(black_box(i))); });
+        samples.push(start.elapsed() / batch as u32);
+    }
+    samples.sort();
+    Stats {
+        iterations: batch * samples.len() as u64,
+        min: samples[0],
+        median: samples[samples.len() / 2],
+        mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+    }
+}
+
+fn report(name: &str, stats: Stats) -> Stats {
+    println!("bench {:<32} median {:>12.2?}   min {:>12.2?}   mean {:>12.2?}   ({} iterations)",
+             name, stats.median, stats.min, stats.mean, stats.iterations);
+    stats
+}
+
+pub fn bench<T>(name: &str, mut f: impl FnMut() -> T) -> Stats {
+    report(name, run(measure_time(), || (), |_| f()))
+}
+
+pub fn bench_with_setup<I, T>(name: &str, setup: impl FnMut() -> I, f: impl FnMut(I) -> T) -> Stats {
+    report(name, run(measure_time(), setup, f))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let stats = run(Duration::from_millis(20), || 10_u64, |n| (0..n).sum::<u64>());
+        assert!(stats.iterations >= 10);
+        assert!(stats.min <= stats.median);
+
+        let slow = run(Duration::from_millis(20), || (), |_| std::thread::sleep(Duration::from_micros(200)));
+        assert!(slow.min >= Duration::from_micros(200));
+    }
+}
@@ -0,0 +1,38 @@
+// Checked arithmetic on the answers: a value out of range panics with an `Overflow` payload instead
+// of wrapping in any build, the runner reports it as an overflow of the day and part. The `checked`
+// profile adds the overflow checks of debug builds to all the other arithmetic.
+
+use std::{any, fmt::Display, panic};
+
+// What overflowed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow(pub String);
+
+pub fn overflow(what: String) -> ! {
+    panic::panic_any(Overflow(what))
+}
+
+// The result of a `checked_*` operation, e.g. `check(presses.checked_add(1), "the button presses")`
+pub fn check<T>(value: Option<T>, what: &str) -> T {
+    value.unwrap_or_else(|| overflow(what.to_string()))
+}
+
+// An answer counted in a wider type, unlike `as` it never wraps
+pub fn narrow<T: TryFrom<U>, U: Copy + Display>(value: U) -> T {
+    T::try_from(value).unwrap_or_else(|_| overflow(format!("{} does not fit in {}", value, any::type_name::<T>())))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn narrowing() {
+        assert_eq!(narrow::<i32, usize>(46), 46);
+        let payload = panic::catch_unwind(|| narrow::<i32, i64>(1 << 40)).unwrap_err();
+        assert_eq!(payload.downcast_ref::<Overflow>(), Some(&Overflow("1099511627776 does not fit in i32".to_string())));
+        let payload = panic::catch_unwind(|| check(u8::MAX.checked_add(1), "the counter")).unwrap_err();
+        assert_eq!(payload.downcast_ref::<Overflow>(), Some(&Overflow("the counter".to_string())));
+    }
+}
@@ -0,0 +1,89 @@
+// Fits running times measured on inputs of growing size n to the usual complexity classes
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Constant,
+    Logarithmic,
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+}
+
+impl Class {
+    pub const ALL: [Class; 6] = [Class::Constant, Class::Logarithmic, Class::Linear, Class::Linearithmic, Class::Quadratic, Class::Cubic];
+
+    fn cost(&self, n: f64) -> f64 {
+        match self {
+            Class::Constant => 1.0,
+            Class::Logarithmic => n.max(2.0).ln(),
+            Class::Linear => n,
+            Class::Linearithmic => n * n.max(2.0).ln(),
+            Class::Quadratic => n * n,
+            Class::Cubic => n * n * n,
+        }
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match self {
+            Class::Constant => "O(1)",
+            Class::Logarithmic => "O(log n)",
+            Class::Linear => "O(n)",
+            Class::Linearithmic => "O(n log n)",
+            Class::Quadratic => "O(n^2)",
+            Class::Cubic => "O(n^3)",
+        })
+    }
+}
+
+// Least squares fit of time = c * cost(n) for every class on (n, time) samples, best first. The
+// errors are relative (RMS of time / fitted - 1) as the times span several magnitudes.
+pub fn fit(samples: &[(f64, f64)]) -> Vec<(Class, f64)> {
+    let mut fits = Class::ALL.iter()
+        .map(|class| {
+            let ratios = samples.iter().map(|(n, t)| class.cost(*n) / t).collect::<Vec<f64>>();
+            let c = ratios.iter().sum::<f64>() / ratios.iter().map(|r| r * r).sum::<f64>();
+            let error = (ratios.iter().map(|r| (c * r - 1.0).powi(2)).sum::<f64>() / ratios.len() as f64).sqrt();
+            (*class, error)
+        })
+        .collect::<Vec<(Class, f64)>>();
+    fits.sort_by(|a, b| a.1.total_cmp(&b.1));
+    fits
+}
+
+// Slope of log(time) over log(n), the k of time ~ n^k
+pub fn exponent(samples: &[(f64, f64)]) -> f64 {
+    let points = samples.iter().map(|(n, t)| (n.ln(), t.ln())).collect::<Vec<(f64, f64)>>();
+    let len = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / len;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / len;
+    points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum::<f64>()
+        / points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum::<f64>()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classes() {
+        let sizes = [100.0, 200.0, 400.0, 800.0, 1600.0, 3200.0];
+        for class in Class::ALL {
+            let samples = sizes.iter()
+                .enumerate()
+                .map(|(i, n)| (*n, 3e-6 * class.cost(*n) * (1.0 + 0.02 * (i % 2) as f64)))
+                .collect::<Vec<(f64, f64)>>();
+            assert_eq!(fit(&samples)[0].0, class, "{}", class);
+        }
+
+        let quadratic = sizes.iter().map(|n| (*n, 5.0 * n * n)).collect::<Vec<(f64, f64)>>();
+        assert!((exponent(&quadratic) - 2.0).abs() < 1e-9);
+        assert!(fit(&quadratic)[0].1 < 1e-9);
+        assert_eq!(Class::Linearithmic.to_string(), "O(n log n)");
+    }
+}
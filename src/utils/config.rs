@@ -0,0 +1,257 @@
+// Optional project configuration (`aoc.toml`) in a small subset of TOML: `[section]` headers
+// (dotted like `[2023.day11]`), `key = value` lines and `#` comments. Values are integers, floats,
+// booleans, "strings" and single line arrays of those. The runner loads it once, the keys are
+// looked up as `section.key`. Every key has to be a known one holding a value of its `Kind`.
+
+use std::{collections::HashMap, fmt, sync::OnceLock};
+
+use crate::utils::parse::{self, Cursor, ParseError};
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_ints(&self) -> Option<Vec<i64>> {
+        match self {
+            Value::Array(values) => values.iter().map(|v| v.as_int()).collect(),
+            _ => None,
+        }
+    }
+
+    // an array of exactly N integers
+    pub fn as_int_array<const N: usize>(&self) -> Option<[i64; N]> {
+        self.as_ints()?.try_into().ok()
+    }
+}
+
+// What a key holds, counts fit every integer type the runner and the days read them as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Str,
+    Count,
+    Counts(usize),
+    OneOf(&'static [&'static str]),
+}
+
+impl Kind {
+    pub fn accepts(&self, value: &Value) -> bool {
+        let count = |value: &Value| value.as_int().is_some_and(|i| (0..=i64::from(i32::MAX)).contains(&i));
+        match (self, value) {
+            (Kind::Str, Value::Str(_)) => true,
+            (Kind::Count, value) => count(value),
+            (Kind::Counts(n), Value::Array(values)) => values.len() == *n && values.iter().all(count),
+            (Kind::OneOf(choices), Value::Str(s)) => choices.contains(&s.as_str()),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Kind::Str => write!(f, "a string"),
+            Kind::Count => write!(f, "a non-negative integer"),
+            Kind::Counts(n) => write!(f, "an array of {} non-negative integers", n),
+            Kind::OneOf(choices) => write!(f, "one of {}", choices.iter().map(|c| format!("\"{}\"", c)).collect::<Vec<_>>().join(", ")),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Config {
+    values: HashMap<String, Value>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Config, ParseError> {
+        let mut config = Config::default();
+        let mut section = String::new();
+        for line in parse::lines(text) {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix("[") {
+                let name = header.expect_suffix("]")?.trim();
+                if name.is_empty() || !name.as_str().split('.').all(is_key) {
+                    return Err(name.error("invalid section name"));
+                }
+                section = name.as_str().to_string() + ".";
+                continue;
+            }
+            let (key, value) = line.key_value("=")?;
+            if !is_key(key.as_str()) {
+                return Err(key.error("invalid key"));
+            }
+            let name = section.clone() + key.as_str();
+            if config.values.contains_key(&name) {
+                return Err(key.error(format!("duplicate key `{}`", name)));
+            }
+            config.values.insert(name, parse_value(value)?);
+        }
+        Ok(config)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    // Every key against its kind, None for an unknown key
+    pub fn check(&self, kind: impl Fn(&str) -> Option<Kind>) -> Result<(), String> {
+        let mut keys = self.values.keys().collect::<Vec<&String>>();
+        keys.sort();
+        for key in keys {
+            match kind(key) {
+                None => return Err(format!("unknown key `{}`", key)),
+                Some(kind) if !kind.accepts(&self.values[key]) => return Err(format!("`{}` should be {}", key, kind)),
+                Some(_) => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+// everything from a `#` outside of a string
+fn strip_comment(line: Cursor) -> Cursor {
+    let mut quoted = false;
+    for (i, c) in line.as_str().char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return line.split_at(line.as_str()[..i].chars().count()).unwrap().0,
+            _ => (),
+        }
+    }
+    line
+}
+
+fn parse_value(value: Cursor) -> Result<Value, ParseError> {
+    if let Some(items) = value.strip_prefix("[") {
+        let items = items.expect_suffix("]")?;
+        if items.trim().is_empty() {
+            return Ok(Value::Array(vec![]));
+        }
+        return items.split(",").map(|item| parse_value(item.trim())).collect::<Result<_, _>>().map(Value::Array);
+    }
+    if let Some(text) = value.strip_prefix("\"") {
+        return Ok(Value::Str(text.expect_suffix("\"")?.as_str().to_string()));
+    }
+    match value.as_str() {
+        "true" => return Ok(Value::Bool(true)),
+        "false" => return Ok(Value::Bool(false)),
+        _ => (),
+    }
+    let number = value.as_str().replace('_', "");
+    if let Ok(i) = number.parse::<i64>() {
+        Ok(Value::Int(i))
+    } else if let Ok(f) = number.parse::<f64>() {
+        Ok(Value::Float(f))
+    } else {
+        Err(value.error(format!("cannot parse `{}`", value.as_str())))
+    }
+}
+
+// Only the first configuration is kept
+pub fn set(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+pub fn get(key: &str) -> Option<&'static Value> {
+    CONFIG.get()?.get(key)
+}
+
+pub fn param_key(year: u16, day: u8, name: &str) -> String {
+    format!("{}.day{:02}.{}", year, day, name)
+}
+
+// A per-day parameter with its default, e.g. `param(2023, 11, "expansion", ...)` reads
+// `[2023.day11] expansion`. The value was checked against the kind the calendar declares for it
+// when the configuration was loaded.
+pub fn param<T>(year: u16, day: u8, name: &str, convert: impl Fn(&Value) -> Option<T>, default: T) -> T {
+    let key = param_key(year, day, name);
+    match get(&key) {
+        Some(value) => convert(value).unwrap_or_else(|| panic!("`{}` was checked as another kind than {:?}", key, value)),
+        None => default,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        let config = Config::parse("# runner defaults
+inputs = \"./my inputs#1\"   # not a comment inside the string
+threads = 4
+timeout = 1_500
+
+[2023.day11]
+expansion = [2, 1_000_000]
+[2023.day21]
+steps = 64
+ratio = 0.5
+flags = [true, false]
+empty = []").unwrap();
+        assert_eq!(config.get("inputs").and_then(|v| v.as_str()), Some("./my inputs#1"));
+        assert_eq!(config.get("timeout").and_then(|v| v.as_int()), Some(1500));
+        assert_eq!(config.get("2023.day11.expansion").and_then(|v| v.as_int_array::<2>()), Some([2, 1000000]));
+        assert_eq!(config.get("2023.day11.expansion").and_then(|v| v.as_int_array::<3>()), None);
+        assert_eq!(config.get("2023.day21.steps"), Some(&Value::Int(64)));
+        assert_eq!(config.get("2023.day21.ratio"), Some(&Value::Float(0.5)));
+        assert_eq!(config.get("2023.day21.flags"), Some(&Value::Array(vec![Value::Bool(true), Value::Bool(false)])));
+        assert_eq!(config.get("2023.day21.empty"), Some(&Value::Array(vec![])));
+        assert_eq!(config.get("steps"), None);
+
+        let err = Config::parse("threads = 4\n[day 11]").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(Config::parse("a = 1\na = 2").unwrap_err().message, "duplicate key `a`");
+        assert_eq!(Config::parse("a = [1, x]").unwrap_err().to_string(), "line 1, column 9: cannot parse `x`");
+        assert!(Config::parse("a = \"open").is_err());
+        assert!(Config::parse("just a line").is_err());
+    }
+
+    #[test]
+    fn checking() {
+        let kind = |key: &str| match key {
+            "inputs" => Some(Kind::Str),
+            "threads" => Some(Kind::Count),
+            "format" => Some(Kind::OneOf(&["ppm", "pgm"])),
+            "2023.day11.expansion" => Some(Kind::Counts(2)),
+            _ => None,
+        };
+        let check = |text: &str| Config::parse(text).unwrap().check(kind);
+        assert_eq!(check("inputs = \"in\"\nthreads = 0\nformat = \"pgm\"\n[2023.day11]\nexpansion = [2, 10]"), Ok(()));
+        assert_eq!(check("threads = -1"), Err("`threads` should be a non-negative integer".to_string()));
+        assert_eq!(check("threads = 4_000_000_000"), Err("`threads` should be a non-negative integer".to_string()));
+        assert_eq!(check("format = \"png\""), Err("`format` should be one of \"ppm\", \"pgm\"".to_string()));
+        assert_eq!(check("[2023.day11]\nexpansion = [2]"), Err("`2023.day11.expansion` should be an array of 2 non-negative integers".to_string()));
+        assert_eq!(check("[2023.day11]\nexpanse = 2"), Err("unknown key `2023.day11.expanse`".to_string()));
+    }
+}
@@ -0,0 +1,71 @@
+// Graphviz DOT writer for the graph-shaped puzzles, `<day> --dot` prints the parsed structure
+
+use std::fmt;
+
+pub struct Graph {
+    nodes: Vec<(String, String)>,
+    edges: Vec<(String, String, Option<String>)>,
+}
+
+impl Graph {
+    pub fn new() -> Graph {
+        Graph { nodes: vec![], edges: vec![] }
+    }
+
+    // `shape` is a Graphviz node shape (box, circle, doublecircle, diamond...)
+    pub fn node(&mut self, id: &str, shape: &str) {
+        self.nodes.push((id.to_string(), shape.to_string()));
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, label: Option<String>) {
+        self.edges.push((from.to_string(), to.to_string(), label));
+    }
+}
+
+impl Default for Graph {
+    fn default() -> Graph {
+        Graph::new()
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl fmt::Display for Graph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "digraph {{")?;
+        for (id, shape) in self.nodes.iter() {
+            writeln!(f, "    {} [shape={}];", quote(id), shape)?;
+        }
+        for (from, to, label) in self.edges.iter() {
+            match label {
+                Some(label) => writeln!(f, "    {} -> {} [label={}];", quote(from), quote(to), quote(label))?,
+                None => writeln!(f, "    {} -> {};", quote(from), quote(to))?,
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writer() {
+        let mut graph = Graph::new();
+        graph.node("in", "box");
+        graph.node("A", "doublecircle");
+        graph.edge("in", "A", Some("x<\"1\"".to_string()));
+        graph.edge("in", "A", None);
+        assert_eq!(graph.to_string(), "digraph {
+    \"in\" [shape=box];
+    \"A\" [shape=doublecircle];
+    \"in\" -> \"A\" [label=\"x<\\\"1\\\"\"];
+    \"in\" -> \"A\";
+}
+");
+    }
+}
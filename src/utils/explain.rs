@@ -0,0 +1,77 @@
+// Per-item trace of the contributions to the answers, switched on by `--explain`. Every traced item
+// is one tab separated line: part, item, how its contribution was found and the contribution.
+// `capture` collects the trace of the current thread instead of printing it, for the snapshots.
+
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+// Also on while the current thread is captured
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed) || CAPTURED.with_borrow(|captured| captured.is_some())
+}
+
+pub fn line(part: u8, item: impl Display, detail: impl Display, contribution: impl Display) -> String {
+    format!("part {}\t{}\t{}\t{}", part, item, detail, contribution)
+}
+
+// The days call it for every item they add up, behind `enabled()`: the arguments are evaluated
+// before the call even when the trace is off
+pub fn trace(part: u8, item: impl Display, detail: impl Display, contribution: impl Display) {
+    let captured = CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(out) => {
+            *out += &line(part, &item, &detail, &contribution);
+            out.push('\n');
+            true
+        },
+        None => false,
+    });
+    if !captured && ENABLED.load(Ordering::Relaxed) {
+        println!("{}", line(part, item, detail, contribution));
+    }
+}
+
+// The trace of `f` whether `--explain` is on or not, only the current thread is traced
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, String) {
+    let previous = CAPTURED.replace(Some(String::new()));
+    let result = f();
+    let trace = CAPTURED.replace(previous).unwrap_or_default();
+    (result, trace)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format() {
+        assert_eq!(line(2, format_args!("game {}", 3), "minimum cubes [20, 13, 6]", 1560),
+                   "part 2\tgame 3\tminimum cubes [20, 13, 6]\t1560");
+    }
+
+    #[test]
+    fn capturing() {
+        assert!(!enabled());
+        let (answer, trace) = capture(|| {
+            assert!(enabled());
+            trace(1, "a", "first", 1);
+            trace(1, "b", "second", 2);
+            3
+        });
+        assert_eq!(answer, 3);
+        assert_eq!(trace, "part 1\ta\tfirst\t1\npart 1\tb\tsecond\t2\n");
+    }
+}
@@ -0,0 +1,114 @@
+// Mutation fuzzer for the input parsers, runs as a plain test. Inputs start from a corpus of valid
+// examples and go through random byte mutations and token insertions, the target has to return
+// (with an error or not) instead of panicking. FUZZ_ITERATIONS sets the number of runs (default
+// 1000, crank it up with --release for a long run) and FUZZ_SEED picks another random sequence.
+
+use std::{env, panic::{self, AssertUnwindSafe}};
+
+use crate::utils::rng::Rng;
+
+// Tokens that tend to confuse the puzzle parsers
+const DICTIONARY: &[&str] = &[
+    "\n", "\n\n", " ", ",", ":", ";", "=", "-", "->", " -> ", "{", "}", "(", ")", "<", ">", "|", "#",
+    "%", "&", "0", "-1", "4294967296", "99999999999999999999", "é", "\u{feff}", "\r\n",
+];
+
+pub struct Mutator {
+    rng: Rng,
+    tokens: Vec<Vec<u8>>,
+}
+
+impl Mutator {
+    // Adds the words and symbols of the corpus to the dictionary
+    pub fn new(seed: u64, corpus: &[&str]) -> Mutator {
+        let mut tokens = DICTIONARY.iter().map(|t| t.as_bytes().to_vec()).collect::<Vec<Vec<u8>>>();
+        corpus.iter()
+            .flat_map(|c| c.split(|ch: char| ch.is_whitespace() || ch == ','))
+            .filter(|t| !t.is_empty())
+            .for_each(|t| {
+                if !tokens.iter().any(|x| x == t.as_bytes()) {
+                    tokens.push(t.as_bytes().to_vec());
+                }
+            });
+        Mutator { rng: Rng::new(seed), tokens }
+    }
+
+    pub fn mutate(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut data = input.to_vec();
+        for _ in 0..1 + self.rng.below(4) {
+            let pos = self.rng.below(data.len() + 1);
+            match self.rng.below(7) {
+                0 if pos < data.len() => data[pos] ^= 1 << self.rng.below(8),
+                1 if pos < data.len() => data[pos] = self.rng.below(128) as u8,
+                2 => {
+                    let token = self.rng.choose(&self.tokens).clone();
+                    data.splice(pos..pos, token);
+                },
+                3 if pos < data.len() => {
+                    let end = (pos + 1 + self.rng.below(8)).min(data.len());
+                    data.drain(pos..end);
+                },
+                4 if pos < data.len() => {
+                    let end = (pos + 1 + self.rng.below(16)).min(data.len());
+                    let chunk = data[pos..end].to_vec();
+                    data.splice(pos..pos, chunk);
+                },
+                5 => data.truncate(pos),
+                _ => data.insert(pos, self.rng.below(256) as u8),
+            }
+        }
+        data
+    }
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    env::var(name).ok()
+        .and_then(|s| s.parse::<T>().ok())
+        .unwrap_or(default)
+}
+
+pub fn fuzz(corpus: &[&str], target: impl Fn(&str)) {
+    let iterations = env_or("FUZZ_ITERATIONS", 1000_usize);
+    let seed = env_or("FUZZ_SEED", 0_u64);
+    let mut mutator = Mutator::new(seed, corpus);
+    let mut pool = corpus.iter().map(|c| c.as_bytes().to_vec()).collect::<Vec<Vec<u8>>>();
+
+    for i in 0..iterations {
+        let parent = mutator.rng.choose(&pool).clone();
+        let data = mutator.mutate(&parent);
+        let input = String::from_utf8_lossy(&data).into_owned();
+        if panic::catch_unwind(AssertUnwindSafe(|| target(&input))).is_err() {
+            panic!("Target panicked at iteration {} of FUZZ_SEED={} on input {:?}", i, seed, input);
+        }
+        // mutants are mutated further, keeping the pool bounded
+        if mutator.rng.chance(0.2) {
+            if pool.len() < 256 {
+                pool.push(data);
+            } else {
+                let index = corpus.len() + mutator.rng.below(pool.len() - corpus.len());
+                pool[index] = data;
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutations() {
+        let mut mutator = Mutator::new(0, &["a b,c"]);
+        assert!(mutator.tokens.iter().any(|t| t == b"c"));
+        let mutants = (0..100).map(|_| mutator.mutate(b"abc")).collect::<Vec<Vec<u8>>>();
+        assert!(mutants.iter().any(|m| m.as_slice() != b"abc"));
+        assert!(mutator.mutate(b"").len() <= 200);
+    }
+
+    #[test]
+    #[should_panic(expected = "FUZZ_SEED=")]
+    fn catches_panics() {
+        fuzz(&["12"], |s| { s.parse::<u8>().unwrap(); });
+    }
+}
@@ -0,0 +1,266 @@
+// The Holiday ASCII String Helper (HASH) and the 256-box hash map from day 15 (HASHMAP).
+// Every box keeps its entries in insertion order, replacing a value keeps its slot and removing
+// one shifts the following entries forward, exactly like the lenses in the boxes.
+
+use std::borrow::Borrow;
+
+pub const NUM_BOXES: usize = 256;
+
+pub fn hash(bytes: &[u8]) -> u8 {
+    bytes.iter()
+        .fold(0_u8, |acc, b| acc.wrapping_add(*b).wrapping_mul(17))
+}
+
+pub trait HolidayHash {
+    fn holiday_hash(&self) -> u8;
+}
+
+impl HolidayHash for str {
+    fn holiday_hash(&self) -> u8 {
+        hash(self.as_bytes())
+    }
+}
+
+impl HolidayHash for String {
+    fn holiday_hash(&self) -> u8 {
+        hash(self.as_bytes())
+    }
+}
+
+impl<T: HolidayHash + ?Sized> HolidayHash for &T {
+    fn holiday_hash(&self) -> u8 {
+        (**self).holiday_hash()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayMap<K, V> {
+    boxes: Vec<Vec<(K, V)>>,
+    len: usize,
+}
+
+impl<K, V> Default for HolidayMap<K, V> {
+    fn default() -> Self {
+        HolidayMap { boxes: (0..NUM_BOXES).map(|_| vec![]).collect(), len: 0 }
+    }
+}
+
+impl<K: HolidayHash + Eq, V> HolidayMap<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn position<Q>(&self, key: &Q) -> (usize, Option<usize>)
+    where K: Borrow<Q>, Q: HolidayHash + Eq + ?Sized {
+        let box_id = key.holiday_hash() as usize;
+        (box_id, self.boxes[box_id].iter().position(|(k, _)| k.borrow() == key))
+    }
+
+    // Returns the old value if the key was present, the entry keeps its place in the box
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut e) => Some(e.insert(value)),
+            Entry::Vacant(e) => {
+                e.insert(value);
+                None
+            },
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where K: Borrow<Q>, Q: HolidayHash + Eq + ?Sized {
+        let (box_id, index) = self.position(key);
+        let (_, value) = self.boxes[box_id].remove(index?);
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where K: Borrow<Q>, Q: HolidayHash + Eq + ?Sized {
+        let (box_id, index) = self.position(key);
+        Some(&self.boxes[box_id][index?].1)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where K: Borrow<Q>, Q: HolidayHash + Eq + ?Sized {
+        let (box_id, index) = self.position(key);
+        Some(&mut self.boxes[box_id][index?].1)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where K: Borrow<Q>, Q: HolidayHash + Eq + ?Sized {
+        self.position(key).1.is_some()
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let (box_id, index) = self.position(&key);
+        let bucket = &mut self.boxes[box_id];
+        let len = &mut self.len;
+        match index {
+            Some(index) => Entry::Occupied(OccupiedEntry { bucket, index, len }),
+            None => Entry::Vacant(VacantEntry { key, bucket, len }),
+        }
+    }
+
+    // Box by box, in insertion order inside every box
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.boxes.iter()
+            .flatten()
+            .map(|(k, v)| (k, v))
+    }
+
+    // Non-empty boxes as (box number, entries)
+    pub fn boxes(&self) -> impl Iterator<Item = (usize, &[(K, V)])> {
+        self.boxes.iter()
+            .enumerate()
+            .filter(|(_, b)| !b.is_empty())
+            .map(|(i, b)| (i, b.as_slice()))
+    }
+
+    // Sum of (box number + 1) * (slot in the box + 1) * value
+    pub fn focusing_power(&self) -> u64
+    where V: Copy + Into<u64> {
+        self.boxes()
+            .map(|(box_id, entries)| {
+                entries.iter()
+                    .enumerate()
+                    .map(|(slot, (_, v))| (box_id as u64 + 1) * (slot as u64 + 1) * (*v).into())
+                    .sum::<u64>()
+            })
+            .sum()
+    }
+}
+
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    bucket: &'a mut Vec<(K, V)>,
+    index: usize,
+    len: &'a mut usize,
+}
+
+pub struct VacantEntry<'a, K, V> {
+    key: K,
+    bucket: &'a mut Vec<(K, V)>,
+    len: &'a mut usize,
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(e) => e.key(),
+            Entry::Vacant(e) => e.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    pub fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Entry::Occupied(e) = &mut self {
+            f(e.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.bucket[self.index].0
+    }
+
+    pub fn get(&self) -> &V {
+        &self.bucket[self.index].1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.bucket[self.index].1
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.bucket[self.index].1
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        *self.len -= 1;
+        self.bucket.remove(self.index).1
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    // Goes to the back of its box
+    pub fn insert(self, value: V) -> &'a mut V {
+        *self.len += 1;
+        self.bucket.push((self.key, value));
+        &mut self.bucket.last_mut().unwrap().1
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_algorithm() {
+        assert_eq!(hash(b"HASH"), 52);
+        assert_eq!(hash(b"rn"), 0);
+        assert_eq!(hash(b"qp"), 1);
+        assert_eq!("cm".holiday_hash(), 0);
+    }
+
+    #[test]
+    fn box_order() {
+        let mut map = HolidayMap::new();
+        assert_eq!(map.insert("rn", 1_u32), None);
+        map.insert("cm", 2);
+        map.insert("ot", 9);
+        assert_eq!(map.insert("rn", 5), Some(1));
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&"rn", &5), (&"cm", &2), (&"ot", &9)]);
+        assert_eq!(map.remove("rn"), Some(5));
+        assert_eq!(map.remove("rn"), None);
+        assert_eq!(map.get("cm"), Some(&2));
+        assert!(!map.contains_key("rn"));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.boxes().map(|(i, b)| (i, b.len())).collect::<Vec<_>>(), vec![(0, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn entries() {
+        let mut map: HolidayMap<String, u32> = HolidayMap::new();
+        *map.entry("pc".to_string()).or_insert(0) += 4;
+        map.entry("pc".to_string()).and_modify(|v| *v += 2).or_insert(0);
+        assert_eq!(map.get("pc"), Some(&6));
+        if let Entry::Occupied(e) = map.entry("pc".to_string()) {
+            assert_eq!(e.remove(), 6);
+        }
+        assert!(map.is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+// Dependency-free still images of the grid states, written as binary PPM (color) or PGM (gray)
+
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GRAY: Rgb = [96, 96, 96];
+pub const RED: Rgb = [220, 40, 40];
+pub const GREEN: Rgb = [60, 180, 75];
+pub const YELLOW: Rgb = [255, 210, 0];
+pub const BLUE: Rgb = [40, 90, 220];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Pgm,
+}
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Pgm => "pgm",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image { width, height, pixels: vec![background; width * height] }
+    }
+
+    // One pixel per cell of a grid
+    pub fn from_grid<T>(grid: &[Vec<T>], mut color: impl FnMut(usize, usize, &T) -> Rgb) -> Image {
+        let width = grid.first().map_or(0, |row| row.len());
+        let mut image = Image::new(width, grid.len(), BLACK);
+        for (y, row) in grid.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                image.set(x, y, color(x, y, cell));
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    // Every pixel becomes a factor x factor square, puzzle grids are tiny next to screens
+    pub fn scaled(&self, factor: usize) -> Image {
+        let factor = factor.max(1);
+        let mut image = Image::new(self.width * factor, self.height * factor, BLACK);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                image.set(x, y, self.get(x / factor, y / factor));
+            }
+        }
+        image
+    }
+
+    pub fn write(&self, format: Format, out: &mut impl Write) -> io::Result<()> {
+        match format {
+            Format::Ppm => {
+                write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+                out.write_all(&self.pixels.concat())
+            },
+            Format::Pgm => {
+                write!(out, "P5\n{} {}\n255\n", self.width, self.height)?;
+                out.write_all(&self.pixels.iter().map(|p| luminance(*p)).collect::<Vec<u8>>())
+            },
+        }
+    }
+}
+
+fn luminance([r, g, b]: Rgb) -> u8 {
+    ((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8
+}
+
+// Linear blend from `from` (t = 0) to `to` (t = 1)
+pub fn gradient(from: Rgb, to: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    [0, 1, 2].map(|i| (from[i] as f64 + (to[i] as f64 - from[i] as f64) * t).round() as u8)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoding() {
+        let image = Image::from_grid(&[vec![0, 1], vec![2, 3]], |_, _, c| [*c * 80, 0, 0]);
+        let mut ppm = vec![];
+        image.write(Format::Ppm, &mut ppm).unwrap();
+        assert_eq!(ppm, b"P6\n2 2\n255\n\x00\x00\x00\x50\x00\x00\xa0\x00\x00\xf0\x00\x00");
+
+        let mut pgm = vec![];
+        Image::new(1, 1, WHITE).write(Format::Pgm, &mut pgm).unwrap();
+        assert_eq!(pgm, b"P5\n1 1\n255\n\xff");
+
+        let scaled = image.scaled(2);
+        assert_eq!((scaled.width(), scaled.height()), (4, 4));
+        assert_eq!(scaled.get(3, 1), image.get(1, 0));
+        assert_eq!(gradient(BLACK, [200, 100, 0], 0.5), [100, 50, 0]);
+    }
+}
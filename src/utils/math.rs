@@ -0,0 +1,128 @@
+// Number theory helpers shared by the days. Everything works on i64, intermediate products that
+// could overflow are done in i128.
+
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+pub fn lcm_of(nums: &[i64]) -> i64 {
+    nums.iter().fold(1, |acc, n| lcm(acc, *n))
+}
+
+// Returns (g, x, y) such that a*x + b*y = g = gcd(a, b)
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1_i64, 0_i64);
+    let (mut old_y, mut y) = (0_i64, 1_i64);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// Inverse of a modulo m (m > 0), exists only when a and m are coprime
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    if g != 1 {
+        None
+    } else {
+        Some(x.rem_euclid(m))
+    }
+}
+
+// Chinese Remainder Theorem for congruences x = r (mod m) given as (r, m) with m > 0.
+// The moduli do not need to be pairwise coprime. Returns (x, lcm of moduli) with 0 <= x < lcm,
+// or None when the congruences contradict each other.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter()
+        .try_fold((0_i64, 1_i64), |(r1, m1), &(r2, m2)| {
+            let r2 = r2.rem_euclid(m2);
+            let (g, p, _) = extended_gcd(m1, m2);
+            if (r2 - r1) % g != 0 {
+                return None;
+            }
+            let lcm = m1 / g * m2;
+            let k = ((r2 - r1) / g) as i128 * p as i128 % (m2 / g) as i128;
+            let x = (r1 as i128 + m1 as i128 * k).rem_euclid(lcm as i128) as i64;
+            Some((x, lcm))
+        })
+}
+
+// Synchronises cycles given as (offset, period): cycle i hits at offset_i + k * period_i for k >= 0.
+// Returns the first time all cycles hit together. The offsets do not need to be smaller than the
+// periods, e.g. a path that walks some steps before entering its loop.
+pub fn synchronise(cycles: &[(i64, i64)]) -> Option<i64> {
+    let (x, m) = crt(cycles)?;
+    let min = cycles.iter().map(|(offset, _)| *offset).max().unwrap_or(0);
+    if x >= min {
+        Some(x)
+    } else {
+        Some(x + (min - x + m - 1) / m * m)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm_of(&[2, 3, 4]), 12);
+        assert_eq!(lcm_of(&[7]), 7);
+    }
+
+    #[test]
+    fn extended_euclid() {
+        for (a, b) in [(240, 46), (17, 5), (-30, 12), (0, 9)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn synchronise_cycles() {
+        // day 8 example: ghosts hitting the end every 2 and 3 steps
+        assert_eq!(synchronise(&[(2, 2), (3, 3)]), Some(6));
+        // offsets larger than the periods
+        assert_eq!(synchronise(&[(5, 2), (7, 3)]), Some(7));
+        assert_eq!(synchronise(&[(3, 4), (1, 6)]), Some(7));
+        assert_eq!(synchronise(&[(0, 2), (1, 2)]), None);
+    }
+}
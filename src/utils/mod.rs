@@ -0,0 +1,19 @@
+pub mod math;
+pub mod polygon;
+pub mod parse;
+pub mod polynomial;
+pub mod holiday_map;
+pub mod rng;
+pub mod property;
+pub mod fuzz;
+pub mod bench;
+pub mod assumption;
+pub mod complexity;
+pub mod visualize;
+pub mod image;
+pub mod dot;
+pub mod explain;
+pub mod parallel;
+pub mod checked;
+pub mod config;
+pub mod snapshot;
@@ -0,0 +1,72 @@
+// Data-parallel map over independent items, opt-in with `--threads N` (0 uses every core). The
+// items are split into one contiguous chunk per thread so the results keep their order and match
+// the serial path exactly. Explaining runs serially: the traces are printed or captured by the
+// thread of the caller in the order of the items.
+
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use crate::utils::explain;
+
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+pub fn set_threads(threads: usize) {
+    let threads = if threads == 0 {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        threads
+    };
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    map_with(if explain::enabled() { 1 } else { threads() }, items, f)
+}
+
+fn map_with<T: Sync, R: Send>(threads: usize, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let threads = threads.min(items.len());
+    if threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+    let f = &f;
+    thread::scope(|scope| {
+        let handles = items.chunks(items.len().div_ceil(threads))
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+            .collect::<Vec<_>>();
+        // a panicking item panics the caller like on the serial path
+        handles.into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_as_serial() {
+        let items = (0..103).collect::<Vec<u64>>();
+        let serial = items.iter().map(|i| i * i).collect::<Vec<u64>>();
+        for threads in [1, 2, 4, 7, 200] {
+            assert_eq!(map_with(threads, &items, |i| i * i), serial);
+        }
+        assert!(map_with(4, &[] as &[u64], |i| *i).is_empty());
+        assert!(panic::catch_unwind(|| map_with(4, &items, |i| if *i == 50 { panic!() } else { *i })).is_err());
+    }
+
+    #[test]
+    fn explained_serially() {
+        set_threads(4);
+        let (_, trace) = explain::capture(|| map(&[1, 2, 3], |i| explain::trace(1, i, "", i)));
+        assert_eq!(trace, "part 1\t1\t\t1\npart 1\t2\t\t2\npart 1\t3\t\t3\n");
+    }
+}
@@ -0,0 +1,349 @@
+// Small parsing toolkit for the puzzle inputs. A Cursor is a piece of an input line that remembers
+// where it starts, so every error can point to the line and column that broke the parser.
+
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Line and column are 1-based, column counts chars
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: usize, text: &'a str) -> Cursor<'a> {
+        Cursor { text, line, column: 1 }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError { line: self.line, column: self.column, message: message.into() }
+    }
+
+    // error pointing to the index-th char of the cursor
+    pub fn error_at(&self, index: usize, message: impl Into<String>) -> ParseError {
+        ParseError { line: self.line, column: self.column + index, message: message.into() }
+    }
+
+    // sub cursor of text[start..end] (byte offsets)
+    fn slice(&self, start: usize, end: usize) -> Cursor<'a> {
+        Cursor {
+            text: &self.text[start..end],
+            line: self.line,
+            column: self.column + self.text[..start].chars().count(),
+        }
+    }
+
+    pub fn trim(&self) -> Cursor<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        self.slice(start, end)
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    pub fn first(&self) -> Result<char, ParseError> {
+        self.text.chars().next().ok_or_else(|| self.error("unexpected end of line"))
+    }
+
+    // drops the prefix if it is there
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Cursor<'a>> {
+        if self.text.starts_with(prefix) {
+            Some(self.slice(prefix.len(), self.text.len()))
+        } else {
+            None
+        }
+    }
+
+    pub fn expect(&self, prefix: &str) -> Result<Cursor<'a>, ParseError> {
+        self.strip_prefix(prefix)
+            .ok_or_else(|| self.error(format!("expected `{}`", prefix)))
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Option<Cursor<'a>> {
+        if self.text.ends_with(suffix) {
+            Some(self.slice(0, self.text.len() - suffix.len()))
+        } else {
+            None
+        }
+    }
+
+    pub fn expect_suffix(&self, suffix: &str) -> Result<Cursor<'a>, ParseError> {
+        self.strip_suffix(suffix)
+            .ok_or_else(|| self.error_at(self.len(), format!("expected `{}`", suffix)))
+    }
+
+    // skips the first n chars
+    pub fn skip(&self, n: usize) -> Result<Cursor<'a>, ParseError> {
+        match self.text.char_indices().nth(n) {
+            Some((i, _)) => Ok(self.slice(i, self.text.len())),
+            None if self.len() == n => Ok(self.slice(self.text.len(), self.text.len())),
+            None => Err(self.error(format!("expected at least {} characters", n))),
+        }
+    }
+
+    // first n chars and the rest
+    pub fn split_at(&self, n: usize) -> Result<(Cursor<'a>, Cursor<'a>), ParseError> {
+        let rest = self.skip(n)?;
+        let at = self.text.len() - rest.text.len();
+        Ok((self.slice(0, at), rest))
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(Cursor<'a>, Cursor<'a>), ParseError> {
+        match self.text.find(delimiter) {
+            Some(i) => Ok((self.slice(0, i), self.slice(i + delimiter.len(), self.text.len()))),
+            None => Err(self.error(format!("expected `{}`", delimiter))),
+        }
+    }
+
+    // like split_once but both sides are trimmed, e.g. `x=787` or `AAA = (BBB, CCC)`
+    pub fn key_value(&self, delimiter: &str) -> Result<(Cursor<'a>, Cursor<'a>), ParseError> {
+        let (key, value) = self.split_once(delimiter)?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    pub fn split(&self, delimiter: &'a str) -> impl Iterator<Item = Cursor<'a>> + 'a {
+        let cursor = *self;
+        let mut start = 0;
+        self.text.split(delimiter)
+            .map(move |piece| {
+                let c = cursor.slice(start, start + piece.len());
+                start += piece.len() + delimiter.len();
+                c
+            })
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = Cursor<'a>> + 'a {
+        let cursor = *self;
+        self.text.split_whitespace()
+            .map(move |word| {
+                let start = word.as_ptr() as usize - cursor.text.as_ptr() as usize;
+                cursor.slice(start, start + word.len())
+            })
+    }
+
+    // `name{body}`, returns the name and the body between the outer brackets
+    pub fn braced(&self, open: char, close: char) -> Result<(Cursor<'a>, Cursor<'a>), ParseError> {
+        let start = self.text.find(open)
+            .ok_or_else(|| self.error(format!("expected `{}`", open)))?;
+        if !self.text.ends_with(close) || self.text.len() < start + open.len_utf8() + close.len_utf8() {
+            return Err(self.error_at(self.len(), format!("expected `{}`", close)));
+        }
+        Ok((self.slice(0, start), self.slice(start + open.len_utf8(), self.text.len() - close.len_utf8())))
+    }
+
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        let trimmed = self.trim();
+        trimmed.text.parse::<T>()
+            .map_err(|_| trimmed.error(format!("cannot parse `{}`", trimmed.text)))
+    }
+
+    // whitespace separated numbers
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.words().map(|w| w.parse::<T>()).collect()
+    }
+
+    pub fn numbers_sep<T: FromStr>(&self, delimiter: &'a str) -> Result<Vec<T>, ParseError> {
+        self.split(delimiter).map(|w| w.parse::<T>()).collect()
+    }
+
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.text.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(format!("unexpected `{}`", self.text)))
+        }
+    }
+}
+
+// The input as the parsers expect it: no byte order mark, `\n` line endings, no trailing spaces and
+// a single final newline. Inputs copied from a browser break blank line splitting otherwise. The
+// second value lists what had to be fixed, it is empty for a clean input.
+pub fn normalize(input: &str) -> (String, Vec<String>) {
+    let mut fixes = vec![];
+    let input = match input.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            fixes.push("a byte order mark".to_string());
+            rest
+        },
+        None => input,
+    };
+    if input.contains('\r') {
+        fixes.push("CRLF line endings".to_string());
+    }
+    let mut lines = input.split('\n')
+        .flat_map(|line| line.strip_suffix('\r').unwrap_or(line).split('\r'))
+        .collect::<Vec<&str>>();
+    let spaced = lines.iter().filter(|line| line.ends_with([' ', '\t'])).count();
+    if spaced > 0 {
+        fixes.push(format!("trailing spaces on {} line(s)", spaced));
+    }
+    lines.iter_mut().for_each(|line| *line = line.trim_end_matches([' ', '\t']));
+    // the final newline leaves one empty line behind, it is not a blank line of the input
+    let blank = lines.iter().rev().take_while(|line| line.is_empty()).count().saturating_sub(1);
+    if blank > 0 {
+        fixes.push(format!("{} trailing blank line(s)", blank));
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let mut normalized = lines.join("\n");
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    (normalized, fixes)
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Cursor<'_>> {
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| Cursor::new(i + 1, line))
+}
+
+// Groups of lines separated by blank lines, the groups are never empty
+pub fn sections(input: &str) -> Vec<Vec<Cursor<'_>>> {
+    let mut ret: Vec<Vec<Cursor>> = vec![vec![]];
+    lines(input).for_each(|line| {
+        if line.is_empty() {
+            ret.push(vec![]);
+        } else {
+            ret.last_mut().unwrap().push(line);
+        }
+    });
+    ret.retain(|section| !section.is_empty());
+    ret
+}
+
+// Grid of cells, the closure gets (x, y, char) and rejects unknown chars with None. The grid is never
+// empty, the days index its first row freely.
+pub fn grid<T>(input: &str, mut cell: impl FnMut(usize, usize, char) -> Option<T>) -> Result<Vec<Vec<T>>, ParseError> {
+    let width = input.lines().next().map(|l| l.chars().count()).unwrap_or(0);
+    if width == 0 {
+        return Err(ParseError { line: 1, column: 1, message: "expected a non-empty grid".to_string() });
+    }
+    lines(input)
+        .enumerate()
+        .map(|(y, line)| {
+            if line.len() != width {
+                return Err(line.error(format!("expected {} cells, found {}", width, line.len())));
+            }
+            line.as_str().chars()
+                .enumerate()
+                .map(|(x, c)| cell(x, y, c).ok_or_else(|| line.error_at(x, format!("unexpected `{}`", c))))
+                .collect()
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let line = Cursor::new(3, "px{a<2006:qkq,m>2090:A,rfg}");
+        let (name, body) = line.braced('{', '}').unwrap();
+        assert_eq!(name.as_str(), "px");
+        assert_eq!(body.as_str(), "a<2006:qkq,m>2090:A,rfg");
+        let rules = body.split(",").collect::<Vec<_>>();
+        assert_eq!(rules[2].as_str(), "rfg");
+        assert_eq!(rules[1].error("boom"), ParseError { line: 3, column: 15, message: "boom".to_string() });
+
+        let (steps, color) = Cursor::new(1, "R 6 (#70c710)").skip(2).unwrap().split_once(" ").unwrap();
+        assert_eq!(steps.parse::<u32>(), Ok(6));
+        assert_eq!(color.braced('(', ')').unwrap().1.as_str(), "#70c710");
+        assert_eq!(Cursor::new(1, "a b").split_at(1).unwrap().0.as_str(), "a");
+    }
+
+    #[test]
+    fn numbers() {
+        let line = Cursor::new(1, "Card 1: 41 48 83 | 83 86  6");
+        let (card, numbers) = line.key_value(":").unwrap();
+        assert_eq!(card.words().nth(1).unwrap().parse::<i32>(), Ok(1));
+        let (winning, mine) = numbers.key_value("|").unwrap();
+        assert_eq!(winning.numbers::<i32>(), Ok(vec![41, 48, 83]));
+        assert_eq!(mine.numbers::<i32>(), Ok(vec![83, 86, 6]));
+        assert_eq!(Cursor::new(1, "1,1,3").numbers_sep::<i64>(","), Ok(vec![1, 1, 3]));
+
+        let err = Cursor::new(7, "seeds: 79 x4").key_value(":").unwrap().1.numbers::<i64>().unwrap_err();
+        assert_eq!(err.to_string(), "line 7, column 11: cannot parse `x4`");
+    }
+
+    #[test]
+    fn errors() {
+        let line = Cursor::new(2, "broadcaster a, b");
+        assert_eq!(line.split_once(" -> ").unwrap_err().message, "expected ` -> `");
+        assert_eq!(line.braced('{', '}').unwrap_err().column, 1);
+        assert_eq!(Cursor::new(1, "{x=1").braced('{', '}').unwrap_err().column, 5);
+        assert!(Cursor::new(1, "").first().is_err());
+        assert!(Cursor::new(1, "ab").skip(3).is_err());
+        assert!(Cursor::new(1, "ab").skip(2).unwrap().is_empty());
+        assert_eq!(Cursor::new(1, "%a").expect("&").unwrap_err().message, "expected `&`");
+        assert!(Cursor::new(1, "x").end().is_err());
+    }
+
+    #[test]
+    fn blocks() {
+        let input = "a\nb\n\n\nc\n";
+        let blocks = sections(input);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1][0].line(), 5);
+
+        let g = grid(&input[..3], |_, _, c| if c == 'a' || c == 'b' { Some(c) } else { None }).unwrap();
+        assert_eq!(g, vec![vec!['a'], vec!['b']]);
+        let err = grid(&input[6..], |_, _, c| if c == 'a' { Some(c) } else { None }).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(normalize("a\nb\n"), ("a\nb\n".to_string(), vec![]));
+        assert_eq!(normalize("a\nb"), ("a\nb\n".to_string(), vec![]));
+        assert_eq!(normalize("\u{feff}#.. \r\n  \r\n..#\t\r\n\r\n\r\n"), ("#..\n\n..#\n".to_string(), vec![
+            "a byte order mark".to_string(),
+            "CRLF line endings".to_string(),
+            "trailing spaces on 3 line(s)".to_string(),
+            "2 trailing blank line(s)".to_string(),
+        ]));
+        assert_eq!(sections(&normalize("1\r\n2\r\n \r\n3").0).len(), 2);
+        assert_eq!(normalize("\n\n"), (String::new(), vec!["2 trailing blank line(s)".to_string()]));
+    }
+
+    #[test]
+    fn empty_grids() {
+        let expected = ParseError { line: 1, column: 1, message: "expected a non-empty grid".to_string() };
+        for input in ["", "\n", "\n#.\n"] {
+            assert_eq!(grid(input, |_, _, c| Some(c)).unwrap_err(), expected);
+        }
+    }
+}
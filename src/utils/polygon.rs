@@ -0,0 +1,90 @@
+// Shoelace formula and Pick's theorem for simple polygons with lattice (integer) vertices.
+// Vertices are listed in order (any orientation), the last one connects back to the first.
+
+use crate::utils::math::gcd;
+
+pub type Point = (i64, i64);
+
+// Builds the vertices by walking the relative moves from the origin
+pub fn from_moves(moves: impl IntoIterator<Item = Point>) -> Vec<Point> {
+    let mut pos = (0, 0);
+    moves.into_iter()
+        .map(|(dx, dy)| {
+            pos = (pos.0 + dx, pos.1 + dy);
+            pos
+        })
+        .collect()
+}
+
+// Twice the enclosed area, kept integer so nothing is lost for half-integer areas
+pub fn double_area(vertices: &[Point]) -> i64 {
+    let n = vertices.len();
+    (0..n).map(|i| {
+            let (x1, y1) = vertices[i];
+            let (x2, y2) = vertices[(i + 1) % n];
+            x1 * y2 - x2 * y1
+        })
+        .sum::<i64>()
+        .abs()
+}
+
+pub fn area(vertices: &[Point]) -> f64 {
+    double_area(vertices) as f64 / 2.0
+}
+
+// Lattice points lying on the edges, for rectilinear polygons this is just the perimeter
+pub fn boundary_points(vertices: &[Point]) -> i64 {
+    let n = vertices.len();
+    (0..n).map(|i| {
+            let (x1, y1) = vertices[i];
+            let (x2, y2) = vertices[(i + 1) % n];
+            gcd(x2 - x1, y2 - y1)
+        })
+        .sum()
+}
+
+// Pick's theorem: A = I + B/2 - 1
+pub fn interior_points(vertices: &[Point]) -> i64 {
+    (double_area(vertices) - boundary_points(vertices) + 2) / 2
+}
+
+pub fn total_points(vertices: &[Point]) -> i64 {
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectilinear() {
+        // 3x2 rectangle
+        let rect = [(0, 0), (3, 0), (3, 2), (0, 2)];
+        assert_eq!(double_area(&rect), 12);
+        assert_eq!(area(&rect), 6.0);
+        assert_eq!(boundary_points(&rect), 10);
+        assert_eq!(interior_points(&rect), 2);
+        assert_eq!(total_points(&rect), 12);
+
+        // L shape walked clockwise
+        let l_shape = from_moves([(2, 0), (0, 1), (-1, 0), (0, 1), (-1, 0), (0, -2)]);
+        assert_eq!(l_shape.last(), Some(&(0, 0)));
+        assert_eq!(double_area(&l_shape), 6);
+        assert_eq!(boundary_points(&l_shape), 8);
+        assert_eq!(interior_points(&l_shape), 0);
+    }
+
+    #[test]
+    fn general() {
+        let triangle = [(0, 0), (4, 0), (0, 4)];
+        assert_eq!(double_area(&triangle), 16);
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), 3);
+        assert_eq!(total_points(&triangle), 15);
+
+        let half = [(0, 0), (1, 0), (0, 1)];
+        assert_eq!(area(&half), 0.5);
+        assert_eq!(interior_points(&half), 0);
+    }
+}
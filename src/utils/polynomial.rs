@@ -0,0 +1,254 @@
+// Exact polynomial interpolation and extrapolation. Samples at consecutive integer indices go
+// through finite-difference tables and stay in integers, arbitrary sample points use Newton's
+// divided differences over rationals.
+
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+
+use crate::utils::checked;
+
+// Always normalized: den > 0 and gcd(num, den) = 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+fn gcd_i128(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Rational {
+    pub fn new(num: i128, den: i128) -> Rational {
+        assert!(den != 0, "Zero denominator");
+        let g = gcd_i128(num, den).max(1) * den.signum();
+        Rational { num: num / g, den: den / g }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    pub fn to_integer(&self) -> Option<i64> {
+        if self.is_integer() {
+            i64::try_from(self.num).ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Rational {
+        Rational { num: n as i128, den: 1 }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+    fn add(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.den + other.num * self.den, self.den * other.den)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, other: Rational) -> Rational {
+        self + (-other)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Rational {
+        Rational { num: -self.num, den: self.den }
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.num, self.den * other.den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    fn div(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.den, self.den * other.num)
+    }
+}
+
+// Rows of successive differences, the first row are the values themselves. Stops at the first
+// all-zero row (kept) or when a row has a single value.
+pub fn difference_table(values: &[i64]) -> Vec<Vec<i64>> {
+    let mut table = vec![values.to_vec()];
+    loop {
+        let last = table.last().unwrap();
+        if last.len() <= 1 || last.iter().all(|v| *v == 0) {
+            break;
+        }
+        let next = last.windows(2)
+            .map(|w| w[1] - w[0])
+            .collect::<Vec<i64>>();
+        table.push(next);
+    }
+    table
+}
+
+// Degree of the lowest polynomial through the values, None when there are no values
+pub fn degree(values: &[i64]) -> Option<usize> {
+    let table = difference_table(values);
+    match table.last() {
+        None => None,
+        Some(row) if row.is_empty() => None,
+        Some(row) if row.iter().all(|v| *v == 0) => Some(table.len().saturating_sub(2)),
+        Some(_) => Some(table.len() - 1),
+    }
+}
+
+// Value at an arbitrary (also negative) index of the polynomial through values[0], values[1], ...
+// (0 for no values).
+// Newton's forward formula p(x) = sum C(x, k) * diff_k(0), binomials of integers stay integers.
+pub fn extrapolate(values: &[i64], index: i64) -> i64 {
+    let x = index as i128;
+    let mut binomial: i128 = 1;
+    checked::narrow(difference_table(values).iter()
+        .enumerate()
+        .map(|(k, row)| {
+            if k > 0 {
+                binomial = binomial * (x - k as i128 + 1) / k as i128;
+            }
+            binomial * row.first().copied().unwrap_or(0) as i128
+        })
+        .sum::<i128>())
+}
+
+// Polynomial through arbitrary points in Newton form, built from divided differences
+#[derive(Debug, Clone, PartialEq)]
+pub struct Newton {
+    xs: Vec<Rational>,
+    coefs: Vec<Rational>,
+}
+
+impl Newton {
+    // The x coordinates have to be distinct
+    pub fn fit(points: &[(i64, i64)]) -> Newton {
+        let xs = points.iter().map(|(x, _)| Rational::from(*x)).collect::<Vec<_>>();
+        let mut coefs = points.iter().map(|(_, y)| Rational::from(*y)).collect::<Vec<_>>();
+        for level in 1..points.len() {
+            for i in (level..points.len()).rev() {
+                coefs[i] = (coefs[i] - coefs[i - 1]) / (xs[i] - xs[i - level]);
+            }
+        }
+        Newton { xs, coefs }
+    }
+
+    pub fn eval(&self, x: i64) -> Rational {
+        let x = Rational::from(x);
+        self.coefs.iter()
+            .zip(self.xs.iter())
+            .rev()
+            .fold(Rational::from(0), |acc, (c, xi)| acc * (x - *xi) + *c)
+    }
+
+    // Coefficients in the monomial basis, lowest degree first
+    pub fn coefficients(&self) -> Vec<Rational> {
+        let mut ret = vec![Rational::from(0); self.coefs.len()];
+        // Horner in the Newton basis, multiplying the polynomial by (x - xi) at each step
+        for (c, xi) in self.coefs.iter().zip(self.xs.iter()).rev() {
+            let mut next = vec![Rational::from(0); ret.len()];
+            for k in 0..ret.len() {
+                if k + 1 < next.len() {
+                    next[k + 1] = next[k + 1] + ret[k];
+                }
+                next[k] = next[k] - ret[k] * *xi;
+            }
+            next[0] = next[0] + *c;
+            ret = next;
+        }
+        ret
+    }
+}
+
+// Lagrange interpolation through the points evaluated at x
+pub fn lagrange(points: &[(i64, i64)], x: i64) -> Rational {
+    let x = Rational::from(x);
+    points.iter()
+        .enumerate()
+        .map(|(i, (xi, yi))| {
+            points.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Rational::from(*yi), |acc, (_, (xj, _))| {
+                    acc * (x - Rational::from(*xj)) / (Rational::from(*xi) - Rational::from(*xj))
+                })
+        })
+        .fold(Rational::from(0), |acc, term| acc + term)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rationals() {
+        assert_eq!(Rational::new(2, -4), Rational::new(-1, 2));
+        assert_eq!(Rational::new(1, 2) + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(Rational::new(1, 2) / Rational::new(1, 4), Rational::from(2));
+        assert_eq!(Rational::new(6, 3).to_integer(), Some(2));
+        assert_eq!(Rational::new(1, 3).to_integer(), None);
+        assert_eq!(Rational::new(-1, 3).to_string(), "-1/3");
+    }
+
+    #[test]
+    fn finite_differences() {
+        assert_eq!(difference_table(&[0, 3, 6, 9]), vec![vec![0, 3, 6, 9], vec![3, 3, 3], vec![0, 0]]);
+        assert_eq!(degree(&[1, 3, 6, 10, 15, 21]), Some(2));
+        assert_eq!(degree(&[5, 5]), Some(0));
+        assert_eq!(degree(&[]), None);
+        // day 9 example
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], 6), 68);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], -1), 5);
+        // x^3 far away from the samples
+        assert_eq!(extrapolate(&[0, 1, 8, 27], 100), 1_000_000);
+        assert_eq!(extrapolate(&[0, 1, 8, 27], -3), -27);
+    }
+
+    #[test]
+    fn interpolation() {
+        // the day 21 shape: quadratic sampled with a stride
+        let f = |x: i64| 3 * x * x - 7 * x + 11;
+        let points = [(65, f(65)), (196, f(196)), (327, f(327))];
+        let poly = Newton::fit(&points);
+        assert_eq!(poly.eval(26501365), Rational::from(f(26501365)));
+        assert_eq!(lagrange(&points, 26501365), Rational::from(f(26501365)));
+        assert_eq!(poly.coefficients(), vec![Rational::from(11), Rational::from(-7), Rational::from(3)]);
+
+        // line through (0, 0) and (2, 1) has non-integer values
+        assert_eq!(Newton::fit(&[(0, 0), (2, 1)]).eval(1), Rational::new(1, 2));
+        assert_eq!(lagrange(&[(0, 0), (2, 1)], 3), Rational::new(3, 2));
+    }
+}
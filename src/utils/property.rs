@@ -0,0 +1,52 @@
+// Differential testing: feed seeded random inputs to a slow but obviously correct reference and to
+// the optimized solver and compare. Every case uses its own seed, a failing case prints the seed and
+// the input, PROPTEST_SEED=<seed> replays just that case and PROPTEST_CASES overrides the count.
+
+use std::{env, fmt::Debug};
+
+use crate::utils::rng::Rng;
+
+fn seeds(cases: usize) -> Vec<u64> {
+    if let Some(seed) = env::var("PROPTEST_SEED").ok().and_then(|s| s.parse::<u64>().ok()) {
+        return vec![seed];
+    }
+    let cases = env::var("PROPTEST_CASES").ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(cases);
+    (0..cases as u64).collect()
+}
+
+pub fn differential<I, T>(cases: usize,
+                          mut generate: impl FnMut(&mut Rng) -> I,
+                          reference: impl Fn(&I) -> T,
+                          optimized: impl Fn(&I) -> T)
+where I: Debug, T: PartialEq + Debug {
+    for seed in seeds(cases) {
+        let input = generate(&mut Rng::new(seed));
+        let expected = reference(&input);
+        let actual = optimized(&input);
+        if expected != actual {
+            panic!("Case with PROPTEST_SEED={} failed: reference {:?} != optimized {:?}\ninput: {:#?}",
+                   seed, expected, actual, input);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agreeing_solvers() {
+        differential(50, |rng| (0..rng.below(20)).map(|_| rng.range(-100..100)).collect::<Vec<i64>>(),
+                     |v| v.iter().sum::<i64>(),
+                     |v| v.iter().copied().reduce(|acc, x| acc + x).unwrap_or(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "PROPTEST_SEED=")]
+    fn disagreeing_solvers() {
+        differential(50, |rng| rng.range(0..10), |x| *x, |x| x.min(&5).to_owned());
+    }
+}
@@ -0,0 +1,126 @@
+// Small seeded PRNG (SplitMix64) so generated inputs are reproducible without external crates.
+// Not suitable for anything but test data. Also holds the random shapes shared by several generators.
+
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in 0..n, n > 0
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "Empty range");
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as i64
+    }
+
+    // true with probability p
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// Random simple rectilinear polygon as relative moves, 4 moves per column. The polygon lies between a
+// top and a bottom profile over columns of random width; neighbouring columns overlap and no height
+// repeats, so the boundary never touches itself. Widths and height changes are in 1..=scale.
+pub fn random_rectilinear(rng: &mut Rng, columns: usize, scale: i64) -> Vec<(i64, i64)> {
+    let columns = columns.max(1);
+    let scale = scale.max(1);
+    let mut profile = vec![(0, rng.range(1..scale + 1))];
+    while profile.len() < columns {
+        let (bottom, top) = *profile.last().unwrap();
+        let new_bottom = rng.range(bottom - scale..top);
+        let new_top = rng.range(new_bottom.max(bottom) + 1..top + scale + 1);
+        if new_bottom != bottom && new_top != top {
+            profile.push((new_bottom, new_top));
+        }
+    }
+    let widths = (0..columns).map(|_| rng.range(1..scale + 1)).collect::<Vec<i64>>();
+
+    let mut moves = vec![(0, profile[0].1 - profile[0].0)];
+    for i in 0..columns {
+        moves.push((widths[i], 0));
+        if i + 1 < columns {
+            moves.push((0, profile[i + 1].1 - profile[i].1));
+        }
+    }
+    moves.push((0, profile[columns - 1].0 - profile[columns - 1].1));
+    for i in (0..columns).rev() {
+        moves.push((-widths[i], 0));
+        if i > 0 {
+            moves.push((0, profile[i - 1].0 - profile[i].0));
+        }
+    }
+    moves
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::polygon;
+
+    #[test]
+    fn reproducible() {
+        let a = (0..5).map(|_| Rng::new(42).next_u64()).collect::<Vec<u64>>();
+        assert!(a.iter().all(|x| *x == a[0]));
+        let mut rng = Rng::new(7);
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+
+    #[test]
+    fn bounds() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-5..5).contains(&rng.range(-5..5)));
+        }
+        let mut items = (0..10).collect::<Vec<i32>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<i32>>());
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+
+    #[test]
+    fn random_polygons() {
+        let mut rng = Rng::new(5);
+        for columns in 1..10 {
+            let moves = random_rectilinear(&mut rng, columns, 4);
+            assert_eq!(moves.len(), 4 * columns);
+            assert!(moves.iter().all(|(dx, dy)| (*dx == 0) != (*dy == 0)));
+            let vertices = polygon::from_moves(moves);
+            assert_eq!(vertices.last(), Some(&(0, 0)));
+            assert!(polygon::interior_points(&vertices) >= 0);
+        }
+    }
+}
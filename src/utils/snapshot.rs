@@ -0,0 +1,68 @@
+// Golden snapshot tests: a rendered grid, an explain trace or a DOT graph is compared with the
+// checked-in snapshots/<name>.snap. UPDATE_SNAPSHOTS=1 rewrites the snapshots that changed (and
+// creates the missing ones) instead of failing, review their diff before committing them.
+
+use std::{env, fs, path::PathBuf};
+
+fn path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("snapshots").join(format!("{}.snap", name))
+}
+
+fn updating() -> bool {
+    env::var("UPDATE_SNAPSHOTS").is_ok_and(|value| value != "0")
+}
+
+// The lines that differ, `-` from the snapshot and `+` from the actual output
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<&str>>();
+    let actual = actual.lines().collect::<Vec<&str>>();
+    let mut out = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(i), actual.get(i));
+        if old != new {
+            out += &format!("line {}\n", i + 1);
+            if let Some(old) = old {
+                out += &format!("- {}\n", old);
+            }
+            if let Some(new) = new {
+                out += &format!("+ {}\n", new);
+            }
+        }
+    }
+    if out.is_empty() {
+        out += "only the final newline differs\n";
+    }
+    out
+}
+
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = path(name);
+    // a checkout with CRLF line endings still matches
+    let expected = fs::read_to_string(&path).ok().map(|text| text.replace("\r\n", "\n"));
+    if expected.as_deref() == Some(actual) {
+        return;
+    }
+    if updating() {
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, actual))
+            .unwrap_or_else(|e| panic!("Cannot write {}: {}", path.display(), e));
+        return;
+    }
+    match expected {
+        None => panic!("Snapshot {} is missing, UPDATE_SNAPSHOTS=1 cargo test creates it", path.display()),
+        Some(expected) => panic!("Snapshot {} differs, UPDATE_SNAPSHOTS=1 cargo test rewrites it\n{}",
+                                 path.display(), diff(&expected, actual)),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffing() {
+        assert_eq!(diff("a\nb\nc\n", "a\nx\nc\nd\n"), "line 2\n- b\n+ x\nline 4\n+ d\n");
+        assert_eq!(diff("a\n", "a"), "only the final newline differs\n");
+    }
+}
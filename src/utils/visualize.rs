@@ -0,0 +1,140 @@
+// Terminal drawing of the grid states of the solutions, switched on by `--visualize`. Frames are
+// printed one below the other, with a frame delay they are animated in place by moving the cursor
+// back up over the previous frame. Colors are only used when stdout is a terminal.
+
+use std::{
+    io::{self, IsTerminal, Write},
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static DELAY_MS: AtomicU64 = AtomicU64::new(0);
+static LAST_FRAME_LINES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+}
+
+impl Color {
+    fn ansi(&self) -> &'static str {
+        match self {
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+            Color::Gray => "\x1b[90m",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Option<Color>,
+}
+
+impl Cell {
+    pub fn plain(ch: char) -> Cell {
+        Cell { ch, color: None }
+    }
+
+    pub fn colored(ch: char, color: Color) -> Cell {
+        Cell { ch, color: Some(color) }
+    }
+}
+
+// What the days implement to get drawn
+pub trait Render {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn cell(&self, x: usize, y: usize) -> Cell;
+}
+
+pub fn render(grid: &(impl Render + ?Sized), colors: bool) -> String {
+    let mut out = String::new();
+    for y in 0..grid.height() {
+        let mut current = None;
+        for x in 0..grid.width() {
+            let cell = grid.cell(x, y);
+            if colors && cell.color != current {
+                out += cell.color.map_or("\x1b[0m", |c| c.ansi());
+                current = cell.color;
+            }
+            out.push(cell.ch);
+        }
+        if current.is_some() {
+            out += "\x1b[0m";
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub fn enable(delay: Option<Duration>) {
+    ENABLED.store(true, Ordering::Relaxed);
+    DELAY_MS.store(delay.map_or(0, |d| d.as_millis() as u64), Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Draws a frame when visualization is on, the days call it at every interesting step
+pub fn frame(title: &str, grid: &(impl Render + ?Sized)) {
+    if !enabled() {
+        return;
+    }
+    let stdout = io::stdout();
+    let picture = render(grid, stdout.is_terminal());
+    let delay = DELAY_MS.load(Ordering::Relaxed);
+    let mut out = stdout.lock();
+    let last_lines = LAST_FRAME_LINES.swap(grid.height() + 1, Ordering::Relaxed);
+    if delay > 0 && last_lines > 0 {
+        // back to the top of the previous frame and clear everything below
+        let _ = write!(out, "\x1b[{}A\x1b[J", last_lines);
+    }
+    let _ = writeln!(out, "--- {}", title);
+    let _ = out.write_all(picture.as_bytes());
+    let _ = out.flush();
+    if delay > 0 {
+        thread::sleep(Duration::from_millis(delay));
+    }
+}
+
+// The next frame starts below the last one instead of drawing over it
+pub fn finish() {
+    LAST_FRAME_LINES.store(0, Ordering::Relaxed);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Checkers(usize);
+
+    impl Render for Checkers {
+        fn width(&self) -> usize { self.0 }
+        fn height(&self) -> usize { 2 }
+        fn cell(&self, x: usize, y: usize) -> Cell {
+            if (x + y).is_multiple_of(2) { Cell::colored('#', Color::Red) } else { Cell::plain('.') }
+        }
+    }
+
+    #[test]
+    fn rendering() {
+        assert_eq!(render(&Checkers(3), false), "#.#\n.#.\n");
+        assert_eq!(render(&Checkers(2), true), "\x1b[31m#\x1b[0m.\n.\x1b[31m#\x1b[0m\n");
+    }
+}
@@ -0,0 +1,71 @@
+// Every Advent of Code year is a namespace of days (`y2023::day05`) registered behind a `Calendar`,
+// the runner picks one with `--year` and the utilities are shared by all of them
+
+pub mod y2023;
+
+use crate::utils::{assumption::Assumption, config::{self, Kind, Value}, dot::Graph, image::Image, parse::ParseError, rng::Rng};
+
+pub type Generator = fn(&mut Rng, usize) -> String;
+pub type Validator = fn(&str) -> Result<Vec<Assumption>, ParseError>;
+pub type Part = fn(&str) -> Result<String, ParseError>;
+pub type Grapher = fn(&str) -> Result<Graph, ParseError>;
+pub type Exporter = fn(&str) -> Result<Vec<(&'static str, Image)>, ParseError>;
+
+// The days a year implements, a day is implemented when it has parts
+pub trait Calendar: Sync {
+    fn title(&self, day: u8) -> Option<&'static str>;
+    fn parts(&self, day: u8) -> Vec<Part>;
+
+    fn generator(&self, _day: u8) -> Option<Generator> {
+        None
+    }
+
+    fn validator(&self, _day: u8) -> Option<Validator> {
+        None
+    }
+
+    fn grapher(&self, _day: u8) -> Option<Grapher> {
+        None
+    }
+
+    fn exporter(&self, _day: u8) -> Option<Exporter> {
+        None
+    }
+
+    // The `[<year>.day<NN>]` keys the day reads from the configuration
+    fn params(&self, _day: u8) -> &'static [(&'static str, Kind)] {
+        &[]
+    }
+}
+
+// The year used when --year is not given
+pub const DEFAULT_YEAR: u16 = 2023;
+
+pub fn calendar(year: u16) -> Option<&'static dyn Calendar> {
+    match year {
+        2023 => Some(&y2023::Calendar),
+        _ => None,
+    }
+}
+
+// The kind of a `<year>.day<NN>.<name>` key of the configuration, None when no day reads it
+pub fn param_kind(key: &str) -> Option<Kind> {
+    let [year, day, name] = key.split('.').collect::<Vec<&str>>()[..] else {
+        return None;
+    };
+    let (year, day) = (year.parse::<u16>().ok()?, day.strip_prefix("day")?.parse::<u8>().ok()?);
+    if config::param_key(year, day, name) != key {
+        return None;
+    }
+    calendar(year)?.params(day).iter().find(|(n, _)| *n == name).map(|(_, kind)| *kind)
+}
+
+// 2023 came first and keeps its inputs right in ./inputs, later years get a subdirectory
+pub fn inputs_dir(year: u16) -> String {
+    let base = config::get("inputs").and_then(Value::as_str).unwrap_or("./inputs");
+    if year == 2023 {
+        base.to_string()
+    } else {
+        format!("{}/{}", base, year)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::utils::{explain, rng::Rng};
+
+pub(crate) fn solve_part_1(input: &str) -> i32 {
+    let digits = input.lines()
+        .map(|l| l.chars().filter(|c| c.is_numeric()).collect())
+        .collect::<Vec<Vec<char>>>();
+    calibration_sum(1, input, digits)
+}
+
+// First and last digit of every line, lines without digits count for nothing
+fn calibration_sum(part: u8, input: &str, digits: Vec<Vec<char>>) -> i32 {
+    input.lines()
+        .zip(digits)
+        .enumerate()
+        .filter(|(_, (_, v))| !v.is_empty())
+        .map(|(i, (l, v))| {
+            let value = (String::from(v[0]) + &v[v.len()-1].to_string()).parse::<i32>().unwrap();
+            if explain::enabled() {
+                explain::trace(part, format_args!("line {}", i + 1),
+                               format_args!("{} has digits {}", l, v.iter().collect::<String>()), value);
+            }
+            value
+        })
+        .sum()
+}
+
+pub(crate) fn solve_part_2(input: &str) -> i32 {
+    let strnum = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+    let strnum_int = ["1", "2", "3", "4", "5", "6", "7", "8", "9"];
+    let digits = input.lines()
+        .map(|l| {
+            let mut str_num = String::new();
+            for (i, c) in l.char_indices() {
+                match c.is_numeric() {
+                    true => str_num.push(c),
+                    false => {
+                        for (j, v) in strnum.iter().enumerate() {
+                            if let Some(x) = l[i..].find(v) {
+                                if x == 0 {
+                                    str_num.push_str(strnum_int[j]);
+                                    break;
+                                }
+                            };
+                        }
+                    }
+                };
+            }
+            str_num.chars().collect()
+        }).collect();
+    calibration_sum(2, input, digits)
+}
+
+const DIGIT_NAMES: &[&str] = &["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+// Lines of letters, digits and spelled out digits, every line holds at least one digit
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let mut line = (0..1 + rng.below(8))
+                .map(|_| match rng.below(4) {
+                    0 => ((b'1' + rng.below(9) as u8) as char).to_string(),
+                    1 => rng.choose(DIGIT_NAMES).to_string(),
+                    _ => ((b'a' + rng.below(26) as u8) as char).to_string(),
+                })
+                .collect::<String>();
+            line.insert(rng.below(line.len() + 1), (b'1' + rng.below(9) as u8) as char);
+            line + "\n"
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_case() {
+        let input1 = ["1abc2" ,"pqr3stu8vwx" ,"a1b2c3d4e5f" ,"treb7uchet"].join("\n");
+        let p1 = solve_part_1(&input1);
+        assert_eq!(p1, 142);
+
+        let input2 = ["two1nine" ,"eightwothree" ,"abcone2threexyz" ,"xtwone3four" ,"4nineeightseven2" ,"zoneight234" ,"7pqrstsixteen"].join("\n");
+        let p2 = solve_part_2(&input2);
+        assert_eq!(p2, 281);
+    }
+}
@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use crate::utils::{checked, config, explain, rng::Rng, parse::{self, Cursor, ParseError}};
+
+// red, green and blue cubes in the bag, `[2023.day02] cubes` in aoc.toml
+pub(crate) const CUBES_LIMIT: [i32; 3] = [12, 13, 14];
+
+pub(crate) fn cubes_limit() -> [i32; 3] {
+    config::param(2023, 2, "cubes", |v| Some(v.as_int_array::<3>()?.map(checked::narrow)), CUBES_LIMIT)
+}
+
+#[derive(Debug, Clone)]
+struct Game {
+    id: i32,
+    instances: Vec<Vec<i32>>,
+}
+
+impl Game {
+    fn max_cubes(&self) -> Vec<i32> {
+        let mut max_v: Vec<i32> = vec![0, 0, 0];
+        for inst in self.instances.iter() {
+            (0..3).for_each(|i| {
+                max_v[i] = std::cmp::max(max_v[i], inst[i]);
+            });
+        }
+        max_v
+    }
+}
+
+impl TryFrom<Cursor<'_>> for Game {
+    type Error = ParseError;
+    fn try_from(line: Cursor) -> Result<Self, Self::Error> {
+        let (game, cubes) = line.key_value(":")?;
+        let id = game.expect("Game ")?.parse::<i32>()?;
+        let instances = cubes.split(";")
+            .map(|ins| {
+                let mut cubes_counts = [0, 0, 0];
+                for c in ins.split(",") {
+                    let (count, cube_type) = c.trim().split_once(" ")?;
+                    let count = count.parse::<i32>()?;
+                    let type_id: usize = match cube_type.as_str() {
+                                "red" => 0, 
+                                "green" => 1,
+                                "blue" => 2,
+                                _ => return Err(cube_type.error("not a valid cube type")),
+                    };
+                    cubes_counts[type_id] = std::cmp::max(count, cubes_counts[type_id]);
+                }
+                Ok(Vec::from(cubes_counts))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Game { id, instances })
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Game::try_from(Cursor::new(1, s))
+    }
+}
+
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse::lines(input).map(Game::try_from).collect()
+}
+
+pub(crate) fn solve_part_1(input: &str, cubes_limit: [i32; 3]) -> Result<i32, ParseError> {
+    Ok(parse_games(input)?.iter()
+        .filter(|g| {
+            let max_cubes = g.max_cubes();
+            let mut flag = true;
+            (0..3).for_each(|i| {
+                flag &= max_cubes[i] <= cubes_limit[i]
+            });
+            if explain::enabled() {
+                explain::trace(1, format_args!("game {}", g.id),
+                               format_args!("minimum cubes {:?} {} the bag", max_cubes, if flag { "fit" } else { "exceed" }),
+                               if flag { g.id } else { 0 });
+            }
+            flag
+        })
+        .map(|g| g.id)
+        .sum())
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i32, ParseError> {
+    Ok(parse_games(input)?.iter()
+        .map(|g| {
+            let max_cubes = g.max_cubes();
+            let mut prod = 1;
+            (0..3).for_each(|i| {
+                prod *= max_cubes[i];
+            });
+            if explain::enabled() {
+                explain::trace(2, format_args!("game {}", g.id), format_args!("minimum cubes {:?}", max_cubes), prod);
+            }
+            prod
+        })
+        .sum())
+}
+
+// `size` games of up to 5 reveals, every reveal shows 1 to 3 colors of up to 20 cubes
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            let reveals = (0..1 + rng.below(5))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    colors[..1 + rng.below(3)].iter()
+                        .map(|color| format!("{} {}", 1 + rng.below(20), color))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .collect::<Vec<String>>();
+            format!("Game {}: {}\n", id, reveals.join("; "))
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{fuzz, snapshot};
+
+    #[test]
+    fn simple_case() {
+        let input1 = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\nGame 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\nGame 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\nGame 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        let p1 = solve_part_1(input1, [12, 13, 14]).unwrap();
+        let p2 = solve_part_2(input1).unwrap();
+        assert_eq!(p1, 8);
+        assert_eq!(p2, 2286);
+    }
+
+    #[test]
+    fn explain_snapshot() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\nGame 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let (_, trace1) = explain::capture(|| solve_part_1(input, [12, 13, 14]));
+        let (_, trace2) = explain::capture(|| solve_part_2(input));
+        snapshot::assert_snapshot("2023/day02_explain", &(trace1 + &trace2));
+    }
+
+    #[test]
+    fn fuzz_parser() {
+        fuzz::fuzz(&["Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+                     "Game 13: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"],
+                   |line| { let _ = Game::from_str(line); });
+    }
+}
@@ -1,18 +1,4 @@
-use std::{fs, fmt};
-
-pub fn solve() {
-    let input = fs::read_to_string("./inputs/input_3.txt")
-        .expect("File not found")
-        .lines()
-        .map(|l: &str| l.to_string())
-        .collect::<Vec<String>>();
-
-    let p1 = solve_part_1(input.clone());
-    let p2 = solve_part_2(input);
-
-    println!("Part 1 solution = {}", p1); 
-    println!("Part 2 solution = {}", p2); 
-}
+use crate::utils::{rng::Rng, visualize::{self, Cell, Color, Render}};
 
 #[derive(Debug, Clone)]
 struct Number {
@@ -33,20 +19,38 @@ enum Symbols {
     Gear,
 }
 
-impl fmt::Debug for Symbols {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            Symbols::Number => write!(f, "N"),
-            Symbols::Ignore => write!(f, "."),
-            Symbols::Symbol => write!(f, "S"),
-            Symbols::Gear   => write!(f, "*"),
+// The schematic with the part numbers green and the other numbers red
+struct Schematic<'a> {
+    input: &'a [&'a str],
+    numbers: &'a [Number],
+}
+
+impl Render for Schematic<'_> {
+    fn width(&self) -> usize {
+        self.input.first().map_or(0, |line| line.len())
+    }
+
+    fn height(&self) -> usize {
+        self.input.len()
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        match self.input[y].chars().nth(x).unwrap_or(' ') {
+            '.' => Cell::plain('.'),
+            c @ '0'..='9' => {
+                let valid = self.numbers.iter().any(|n| n.valid && n.locations.contains(&[y as i32, x as i32]));
+                Cell::colored(c, if valid { Color::Green } else { Color::Red })
+            },
+            '*' => Cell::colored('*', Color::Cyan),
+            c => Cell::colored(c, Color::Yellow),
         }
     }
 }
 
 
-fn solve_part_1(input: Vec<String>) -> i32 {
-    let scheme: Vec<Vec<Symbols>> = input.iter()
+pub(crate) fn solve_part_1(input: &str) -> i32 {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let scheme: Vec<Vec<Symbols>> = lines.iter()
         .map(|s| {
             s.chars()
              .map(|c| {
@@ -68,11 +72,11 @@ fn solve_part_1(input: Vec<String>) -> i32 {
         for x in 0..scheme[0].len() {
             match scheme[y][x] {
                 Symbols::Number => {
-                    current_num.push(input[y].chars().nth(x).unwrap());
+                    current_num.push(lines[y].chars().nth(x).unwrap());
                     locs.push([y as i32, x as i32]);
                 },
                 _ => {
-                    if current_num.len() > 0 {
+                    if !current_num.is_empty() {
                         numbers.push(Number { number: current_num.parse::<i32>().unwrap(), valid: is_number_valid(&locs, &scheme), locations: locs});
                         current_num = String::new();
                         locs = Vec::new();
@@ -80,18 +84,20 @@ fn solve_part_1(input: Vec<String>) -> i32 {
                 }
             }
         }
-        if current_num.len() > 0 {
+        if !current_num.is_empty() {
             numbers.push(Number { number: current_num.parse::<i32>().unwrap(), valid: is_number_valid(&locs, &scheme), locations: locs});
         }
     }
 
+    visualize::frame("part numbers", &Schematic { input: &lines, numbers: &numbers });
+    visualize::finish();
     numbers.iter()
         .filter(|n| n.valid)
         .map(|n| n.number)
         .sum()
 }
 
-fn is_number_valid(locs: &Vec<[i32; 2]>, scheme: &Vec<Vec<Symbols>>) -> bool {
+fn is_number_valid(locs: &[[i32; 2]], scheme: &[Vec<Symbols>]) -> bool {
     locs.iter()
         .any(|loc| {
             for y in (loc[0]-1).max(0)..=(loc[0]+1).min(scheme.len() as i32 - 1) {
@@ -106,8 +112,9 @@ fn is_number_valid(locs: &Vec<[i32; 2]>, scheme: &Vec<Vec<Symbols>>) -> bool {
         })
 }
 
-fn solve_part_2(input: Vec<String>) -> i32 {
-    let scheme: Vec<Vec<Symbols>> = input.iter()
+pub(crate) fn solve_part_2(input: &str) -> i32 {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let scheme: Vec<Vec<Symbols>> = lines.iter()
         .map(|s| {
             s.chars()
              .map(|c| {
@@ -129,11 +136,11 @@ fn solve_part_2(input: Vec<String>) -> i32 {
         for x in 0..scheme[0].len() {
             match scheme[y][x] {
                 Symbols::Number => {
-                    current_num.push(input[y].chars().nth(x).unwrap());
+                    current_num.push(lines[y].chars().nth(x).unwrap());
                     locs.push([y as i32, x as i32]);
                 },
                 _ => {
-                    if current_num.len() > 0 {
+                    if !current_num.is_empty() {
                         numbers.push(Number { number: current_num.parse::<i32>().unwrap(), valid: is_number_valid(&locs, &scheme), locations: locs});
                         current_num = String::new();
                         locs = Vec::new();
@@ -141,15 +148,15 @@ fn solve_part_2(input: Vec<String>) -> i32 {
                 }
             }
         }
-        if current_num.len() > 0 {
+        if !current_num.is_empty() {
             numbers.push(Number { number: current_num.parse::<i32>().unwrap(), valid: is_number_valid(&locs, &scheme), locations: locs});
         }
     }
     
     let mut gears: Vec<Gear> = Vec::new();
-    for y in 0..scheme.len() {
-        for x in 0..scheme[0].len() {
-            if let Symbols::Gear = scheme[y][x] {
+    for (y, row) in scheme.iter().enumerate() {
+        for (x, symbol) in row.iter().enumerate() {
+            if let Symbols::Gear = symbol {
                 gears.push(Gear { gears: numbers.iter()
                     .filter(|n| {
                         n.locations.iter()
@@ -170,6 +177,29 @@ fn solve_part_2(input: Vec<String>) -> i32 {
         .sum()
 }
 
+// Square schematic, numbers are always followed by a `.` so they never run into each other
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(1);
+    (0..width)
+        .map(|_| {
+            let mut row = String::new();
+            while row.len() < width {
+                let len = 1 + rng.below(3);
+                if row.len() + len <= width && rng.chance(0.2) {
+                    row += &(1 + rng.below(10_usize.pow(len as u32) - 1)).to_string();
+                    row += ".";
+                } else if rng.chance(0.1) {
+                    row.push(*rng.choose(&['*', '#', '+', '$', '/', '=', '%', '@', '&']));
+                } else {
+                    row.push('.');
+                }
+            }
+            row.truncate(width);
+            row + "\n"
+        })
+        .collect()
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -177,13 +207,9 @@ mod tests {
 
     #[test]
     fn simple_case() {
-        let input1 = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598.."
-            .to_string()
-            .split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
+        let input1 = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
 
-        let p1 = solve_part_1(input1.clone());
+        let p1 = solve_part_1(input1);
         let p2 = solve_part_2(input1);
         assert_eq!(p1, 4361);
         assert_eq!(p2, 467835);
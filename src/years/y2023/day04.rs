@@ -0,0 +1,126 @@
+use std::{str::FromStr, collections::{BTreeSet, BTreeMap}};
+
+use crate::utils::{explain, rng::Rng, parse::{self, Cursor, ParseError}};
+
+#[derive(Debug, Clone)]
+struct Card {
+    id: i32,
+    winning_num: BTreeSet<i32>,
+    my_num: BTreeSet<i32>,
+}
+
+impl Card {
+    fn get_matches(&self) -> u32 {
+        self.winning_num.intersection(&self.my_num).count() as u32
+    }
+
+    fn get_points(&self) -> i32 {
+        let num_matches = self.get_matches();
+        if num_matches > 0 {
+            2_i32.pow(num_matches-1)
+        } else {
+            0
+        }
+    }
+}
+
+impl TryFrom<Cursor<'_>> for Card {
+    type Error = ParseError;
+    fn try_from(line: Cursor) -> Result<Self, Self::Error> {
+        let (card, numbers) = line.key_value(":")?;
+        let id = card.expect("Card")?.parse::<i32>()?;
+        let (winning_num, my_num) = numbers.key_value("|")?;
+        Ok(Card { id, winning_num: winning_num.numbers::<i32>()?.into_iter().collect(), my_num: my_num.numbers::<i32>()?.into_iter().collect() })
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Card::try_from(Cursor::new(1, s))
+    }
+}
+
+fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse::lines(input).map(Card::try_from).collect()
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i32, ParseError> {
+    Ok(parse_cards(input)?.iter()
+        .map(|c| {
+            let points = c.get_points();
+            if explain::enabled() {
+                explain::trace(1, format_args!("card {}", c.id), format_args!("{} matches", c.get_matches()), points);
+            }
+            points
+        })
+        .sum())
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i32, ParseError> {
+    let cards = parse_cards(input)?;
+    let mut card_counts: BTreeMap<u32, i32> = cards.iter().map(|c| (c.id as u32, 1)).collect();
+    cards.iter()
+        .for_each(|c| {
+            let v = card_counts.get(&(c.id as u32)).unwrap().to_owned();
+            if explain::enabled() {
+                explain::trace(2, format_args!("card {}", c.id), format_args!("{} matches, {} copies", c.get_matches(), v), v);
+            }
+            (1..=c.get_matches()).for_each(|i| {
+                *card_counts.get_mut(&((c.id as u32)+ i)).unwrap() += v;
+            });
+        });
+    Ok(card_counts.iter()
+        .map(|c| c.1)
+        .sum())
+}
+
+// `size` cards, the matches never win copies of cards past the end of the table
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (1..=size)
+        .map(|id| {
+            let mut numbers = (1..100).collect::<Vec<usize>>();
+            rng.shuffle(&mut numbers);
+            let num_winning = 1 + rng.below(10);
+            let num_matches = rng.below(num_winning.min(size - id) + 1);
+            let winning = &numbers[..num_winning];
+            let mut mine = numbers[..num_matches].iter()
+                .chain(numbers[num_winning..num_winning + rng.below(20)].iter())
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<String>>();
+            rng.shuffle(&mut mine);
+            let winning = winning.iter().map(|n| format!("{:>2}", n)).collect::<Vec<String>>();
+            format!("Card {:>3}: {} | {}\n", id, winning.join(" "), mine.join(" "))
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fuzz;
+
+    #[test]
+    fn simple_case() {
+        let input1 = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        let p1 = solve_part_1(input1).unwrap();
+        let p2 = solve_part_2(input1).unwrap();
+        assert_eq!(p1, 13);
+        assert_eq!(p2, 30);
+    }
+
+    #[test]
+    fn fuzz_parser() {
+        fuzz::fuzz(&["Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+                     "Card   3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1"],
+                   |line| { let _ = Card::from_str(line); });
+    }
+}
@@ -0,0 +1,309 @@
+use crate::utils::{explain, parallel, rng::Rng, parse::{self, Cursor, ParseError}};
+
+#[derive(Debug, Clone)]
+struct Range {
+    start_dst: i64,
+    start_src: i64,
+    length: i64,
+}
+
+#[derive(Debug, Clone)]
+struct XRange {
+    start: i64,
+    length: i64,
+}
+
+impl XRange {
+    fn end(&self) -> i64 {
+        self.start + self.length
+    }
+}
+
+impl Range {
+    fn end(&self) -> i64 {
+        self.start_src + self.length
+    }
+
+    fn transform(&self, x:i64) -> Option<i64> {
+        let offset: i64 = x - self.start_src;
+        if (offset >= 0) & (offset < self.length) {
+            Some(self.start_dst+offset)
+        } else { 
+            None
+        }
+    }
+
+    fn transform_range(&self, x: &XRange) -> (Option<XRange>, Vec<XRange>) {
+        let mut res: Vec<XRange> = vec![];
+        let mut valid = None;
+        
+        if x.end() <= self.start_src {
+            res.push(x.clone());
+        }
+
+        if x.start >= self.end() {
+            res.push(x.clone());
+        }
+
+        if (x.start < self.start_src)
+            & ( x.end() > self.start_src) {
+            res.push(XRange { start: x.start, length: self.start_src - x.start });
+            valid = Some(XRange { start: self.start_dst, length: (x.end() - self.start_src).min(self.length) });
+            if x.end() > self.end() {
+                res.push(XRange { start: self.end(), length: x.end() - self.end() });
+            }
+        }
+
+        if (x.start >= self.start_src) 
+            & (x.start < self.end()) {
+            let offset = x.start - self.start_src; 
+            valid = Some(XRange { start: self.start_dst + offset, length: (x.length).min(self.end() - x.start) });
+            if x.end() > self.end() {
+                res.push(XRange { start: self.end(), length: x.end() - self.end() });
+            }
+        }
+
+        (valid, res)
+    }
+
+    fn transform_ranges(&self, x: &[XRange]) -> (Vec<XRange>, Vec<XRange>) {
+        let mut mapped_vec: Vec<XRange> = vec![];
+        let mut rest_vec: Vec<XRange> = vec![];
+        x.iter()
+         .for_each(|xr| {
+             let (o1, mut rest) = self.transform_range(xr);
+             if let Some(x) = o1 {
+                 mapped_vec.push(x);
+             }
+            rest_vec.append(&mut rest);
+         });
+        (mapped_vec, rest_vec)
+    }
+
+}
+
+#[derive(Debug)]
+struct Almanac {
+    seeds_ids: Vec<i64>,
+    mappings: Vec<(String, String, Vec<Range>)>,
+}
+
+fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let sections = parse::sections(input);
+    let seeds_ids = match sections.first().map(|s| s.as_slice()) {
+        Some([line]) => line.key_value(":")?.1.numbers::<i64>()?,
+        _ => return Err(ParseError { line: 1, column: 1, message: "expected a single line with seeds".to_string() }),
+    };
+    
+    let mappings = sections.iter().skip(1)
+        .map(|block| parse_block(block))
+        .collect::<Result<_, _>>()?;
+    Ok(Almanac { seeds_ids, mappings })
+}
+
+fn parse_block(block: &[Cursor]) -> Result<(String, String, Vec<Range>), ParseError> {
+   let (header, lines) = block.split_first().expect("Sections are never empty");
+   let (from, to) = header.expect_suffix(" map:")?.split_once("-to-")?;
+   let ranges: Vec<Range> = lines.iter()
+       .map(|line| {
+           match line.numbers::<i64>()?.as_slice() {
+               [start_dst, start_src, length] => Ok(Range { start_dst: *start_dst, start_src: *start_src, length: *length }),
+               _ => Err(line.error("expected three numbers")),
+           }
+       })
+       .collect::<Result<_, _>>()?;
+
+   Ok((from.as_str().to_owned(), to.as_str().to_owned(), ranges))
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i64, ParseError> {
+    let almanac = parse(input)?; 
+    let locations = parallel::map(&almanac.seeds_ids, |sid| {
+        let mut steps = vec![];
+        let m = almanac.mappings.iter()
+            .fold(*sid, |acc, x| {
+                let rs = &x.2;
+                let mut res = acc;
+                for r in rs {
+                    if let Some(x) = r.transform(acc) {
+                        res = x
+                    }
+                }
+                if explain::enabled() {
+                    steps.push(format!("{} {}", x.1, res));
+                }
+                res
+            });
+        (m, steps)
+    });
+    // traced here so the trace keeps the order of the seeds
+    Ok(almanac.seeds_ids.iter()
+        .zip(locations)
+        .map(|(sid, (m, steps))| {
+            if explain::enabled() {
+                explain::trace(1, format_args!("seed {}", sid), steps.join(", "), m);
+            }
+            m
+        })
+        .min()
+        .unwrap_or(0))
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i64, ParseError> {
+    let almanac = parse(input)?; 
+    // First brute force solution, tooked ~ 80 min 
+    // almanac.seeds_ids.iter().enumerate().filter(|(i, v)| i % 2 == 0)
+    //     .map(|(i, start)| {
+    //         println!("{}..{}", *start, *start+almanac.seeds_ids[i+1]);
+    //         ((*start)..*start+almanac.seeds_ids[i+1]).map(|sid| {
+    //                 let m = almanac.mappings.iter()
+    //                     .fold(sid, |acc, x| {
+    //                         let rs = &x.2;
+    //                         let mut res = acc;
+    //                         for r in rs {
+    //                             if let Some(x) = r.transform(acc) {
+    //                                 res = x
+    //                             }
+    //                         }
+    //                         res
+    //                     });
+    //                 m
+    //             })
+    //             .min()
+    //             .unwrap()
+    //     })
+    //     .min()
+    //     .unwrap()
+    
+    if almanac.seeds_ids.len() % 2 == 1 {
+        return Err(ParseError { line: 1, column: 1, message: "seeds should come in pairs".to_string() });
+    }
+    Ok(almanac.seeds_ids.iter().enumerate().filter(|(i, _)| i % 2 == 0)
+        .map(|(i, start)| {
+            let mut mapped_ranges: Vec<XRange> = vec![XRange {start: (*start), length: almanac.seeds_ids[i+1]}];
+            almanac.mappings.iter()
+                .for_each(|vr| {
+                    let rs = &vr.2;
+                    let mut mv: Vec<XRange> = vec![];
+                    rs.iter()
+                      .for_each(|r| {
+                        if !mapped_ranges.is_empty() {
+                            let (mut a, b) = r.transform_ranges(&mapped_ranges);
+                            mv.append(&mut a);
+                            mapped_ranges = b;
+                        }
+                    });
+                    mapped_ranges.append(&mut mv);
+                });
+            let location = mapped_ranges.iter()
+                .map(|xr| xr.start)
+                .min()
+                .unwrap();
+            if explain::enabled() {
+                explain::trace(2, format_args!("seeds {}..{}", start, start + almanac.seeds_ids[i+1]),
+                               format_args!("{} location ranges", mapped_ranges.len()), location);
+            }
+            location
+        })
+        .min()
+        .unwrap_or(0))
+}
+
+const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+// Seed ranges and the seven maps, the source ranges of a map never overlap
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1) as i64;
+    let limit = 10 * size;
+    let seeds = (0..rng.range(1..size / 4 + 2))
+        .map(|_| format!("{} {}", rng.range(0..limit), rng.range(1..size + 1)))
+        .collect::<Vec<String>>();
+    let mut ret = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in CATEGORIES.windows(2) {
+        let mut cuts = (0..rng.range(1..size + 1) * 2)
+            .map(|_| rng.range(0..limit))
+            .collect::<Vec<i64>>();
+        cuts.sort();
+        cuts.dedup();
+        let mut ranges = vec![];
+        for w in cuts.windows(2) {
+            if rng.chance(0.7) {
+                ranges.push(format!("{} {} {}", rng.range(0..limit), w[0], w[1] - w[0]));
+            }
+        }
+        rng.shuffle(&mut ranges);
+        ret += &format!("\n{}-to-{} map:\n", names[0], names[1]);
+        ranges.iter().for_each(|r| ret += &format!("{}\n", r));
+    }
+    ret
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::property;
+
+    #[test]
+    fn simple_case() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 35);
+        assert_eq!(p2, 46);
+    }
+
+    #[test]
+    fn differential() {
+        // reference: the first brute force solution, every seed of the ranges mapped one by one
+        let expand = |input: &String| {
+            let (first, rest) = input.split_once('\n').unwrap();
+            let seeds = first.split_whitespace().skip(1)
+                .map(|n| n.parse::<i64>().unwrap())
+                .collect::<Vec<i64>>();
+            let all_seeds = seeds.chunks(2)
+                .flat_map(|pair| pair[0]..pair[0] + pair[1])
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>();
+            format!("seeds: {}\n{}", all_seeds.join(" "), rest)
+        };
+        property::differential(200,
+            |rng| generate(rng, 8),
+            |input| solve_part_1(&expand(input)).unwrap(),
+            |input| solve_part_2(input).unwrap());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::utils::{rng::Rng, parse::{self, Cursor, ParseError}};
+
+#[derive(Debug)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+impl Race {
+    fn num_solutions(&self) -> u64 {
+        let hold = 1..=self.time-1;
+        hold.into_iter()
+            .map(|t| (self.time-t)*t)
+            .filter(|&d| d > self.distance)
+            .count() as u64
+    }
+}
+
+fn parse_row(line: Cursor) -> Result<Vec<u64>, ParseError> {
+    line.key_value(":")?.1.numbers::<u64>()
+}
+
+fn parse_rows(input: &str) -> Result<[Cursor<'_>; 2], ParseError> {
+    match parse::lines(input).collect::<Vec<Cursor>>().as_slice() {
+        [times, distance] => Ok([*times, *distance]),
+        _ => Err(ParseError { line: 1, column: 1, message: "expected the time and the distance rows".to_string() }),
+    }
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<u64, ParseError> {
+    let [times_row, distance_row] = parse_rows(input)?;
+    let times: Vec<u64> = parse_row(times_row)?;
+    let distance: Vec<u64> = parse_row(distance_row)?;
+    if times.len() != distance.len() {
+        return Err(distance_row.error("expected the same number of times and distances"));
+    }
+    let mut races: Vec<Race> = vec![];
+    for i in 0..times.len() {
+        races.push(Race { time: times[i], distance: distance[i] });
+    }
+    Ok(races.iter().map(|r| r.num_solutions()).product())
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let [times_row, distance_row] = parse_rows(input)?;
+    let i1: String = times_row.key_value(":")?.1.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+    let i2: String = distance_row.key_value(":")?.1.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+    let time: f64 = Cursor::new(times_row.line(), &i1).parse::<u64>()? as f64;
+    let distance: f64 = Cursor::new(distance_row.line(), &i2).parse::<u64>()? as f64;
+
+    // Brute force solution
+    // let r = Race { time : times, distance}; 
+    // r.num_solutions()
+   
+    // solving quadratic equation: velocity * (time_total - time_hold) > distance, where velocity = time_hold
+    // up to some corner cases
+    Ok(((-time - (time.powi(2) - 4_f64*distance).sqrt()) / -2_f64).ceil() as u64 - 
+    ((-time + (time.powi(2) - 4_f64*distance).sqrt()) / -2_f64).ceil() as u64)
+}
+
+// Up to 4 races shorter than 100 ms, every record is beaten by some hold time
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let races = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = 2 + rng.below((size * 10).clamp(1, 98)) as u64;
+            let hold = 1 + rng.below(time as usize / 2) as u64;
+            (time, hold * (time - hold) - rng.below(hold as usize) as u64)
+        })
+        .collect::<Vec<(u64, u64)>>();
+    let times = races.iter().map(|(t, d)| format!(" {:>1$}", t, d.to_string().len())).collect::<String>();
+    let distances = races.iter().map(|(_, d)| format!(" {}", d)).collect::<String>();
+    format!("Time:    {}\nDistance:{}\n", times, distances)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_case() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 288);
+        assert_eq!(p2, 71503);
+    }
+}
@@ -1,18 +1,6 @@
-use std::{fs, cmp::Ordering, collections::HashMap};
+use std::{cmp::Ordering, collections::HashMap};
 
-pub fn solve() {
-    let input = fs::read_to_string("./inputs/input_7.txt")
-        .expect("File not found")
-        .lines()
-        .map(|l: &str| l.to_string())
-        .collect::<Vec<String>>();
-
-    let p1 = solve_part_1(input.clone());
-    let p2 = solve_part_2(input);
-
-    println!("Part 1 solution = {}", p1); 
-    println!("Part 2 solution = {}", p2); 
-}
+use crate::utils::{rng::Rng, parse::{self, Cursor, ParseError}};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
 struct Card {
@@ -78,9 +66,10 @@ impl PartialOrd for Hand {
     }
 }
 
-fn parse_cards(s: &str, joker: bool) -> Vec<Card> {
-    s.chars()
-     .map(|c| match c {
+fn parse_cards(s: Cursor, joker: bool) -> Result<Vec<Card>, ParseError> {
+    s.as_str().chars()
+     .enumerate()
+     .map(|(i, c)| Ok(match c {
         'A' => Card {value: 14},
         'K' => Card {value: 13},
         'Q' => Card {value: 12},
@@ -97,11 +86,26 @@ fn parse_cards(s: &str, joker: bool) -> Vec<Card> {
         '4' => Card {value: 4},
         '3' => Card {value: 3},
         '2' => Card {value: 2},
-        _ => panic!("Not a valid card!")
-     })
+        _ => return Err(s.error_at(i, "not a valid card")),
+     }))
      .collect()
 }
 
+fn parse_hands(input: &str, joker: bool) -> Result<Vec<Hand>, ParseError> {
+    parse::lines(input)
+        .map(|l| {
+            let (cards_str, bid) = l.trim().split_once(" ")?;
+            let cards = parse_cards(cards_str, joker)?;
+            if cards.len() != 5 {
+                return Err(cards_str.error("expected 5 cards"));
+            }
+            let bid: i32 = bid.parse::<i32>()?;
+            let hand_type = get_hand_type(&cards);
+            Ok(Hand {cards, bid, hand_type})
+        })
+        .collect()
+}
+
 fn get_hand_type(cards: &[Card]) -> HandType {
     let mut counts_map = HashMap::<Card, i32>::new();
     let mut num_jokers = 0;
@@ -138,30 +142,27 @@ fn get_hand_type(cards: &[Card]) -> HandType {
     }
 }
 
-fn solve_part_1(input: Vec<String>) -> i64 {
-    let mut hands = input.iter()
-        .map(|l| {
-            let cards = parse_cards(l.split_whitespace().next().unwrap().trim(), false);
-            let bid: i32 = l.split_whitespace().last().unwrap().trim().parse::<i32>().unwrap();
-            let hand_type = get_hand_type(&cards);
-            Hand {cards, bid, hand_type}
-        })
-        .collect::<Vec<Hand>>();
+pub(crate) fn solve_part_1(input: &str) -> Result<i64, ParseError> {
+    let mut hands = parse_hands(input, false)?;
     hands.sort_by(|h1, h2| (*h1).partial_cmp(h2).unwrap());
-    hands.iter().enumerate().map(|(i, h)| h.bid as i64 * (i as i64 + 1)).sum()
+    Ok(hands.iter().enumerate().map(|(i, h)| h.bid as i64 * (i as i64 + 1)).sum())
 }
 
-fn solve_part_2(input: Vec<String>) -> i64 {
-    let mut hands = input.iter()
-        .map(|l| {
-            let cards = parse_cards(l.split_whitespace().next().unwrap().trim(), true);
-            let bid: i32 = l.split_whitespace().last().unwrap().trim().parse::<i32>().unwrap();
-            let hand_type = get_hand_type(&cards);
-            Hand {cards, bid, hand_type}
-        })
-        .collect::<Vec<Hand>>();
+pub(crate) fn solve_part_2(input: &str) -> Result<i64, ParseError> {
+    let mut hands = parse_hands(input, true)?;
     hands.sort_by(|h1, h2| (*h1).partial_cmp(h2).unwrap());
-    hands.iter().enumerate().map(|(i, h)| h.bid as i64 * (i as i64 + 1)).sum()
+    Ok(hands.iter().enumerate().map(|(i, h)| h.bid as i64 * (i as i64 + 1)).sum())
+}
+
+// `size` random hands with bids up to 1000
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let cards = "23456789TJQKA".chars().collect::<Vec<char>>();
+    (0..size.max(1))
+        .map(|_| {
+            let hand = (0..5).map(|_| *rng.choose(&cards)).collect::<String>();
+            format!("{} {}\n", hand, 1 + rng.below(1000))
+        })
+        .collect()
 }
 
 
@@ -175,11 +176,9 @@ mod tests {
 T55J5 684
 KK677 28
 KTJJT 220
-QQQJA 483".split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        let p1 = solve_part_1(input.clone());
-        let p2 = solve_part_2(input);
+QQQJA 483";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
         assert_eq!(p1, 6440);
         assert_eq!(p2, 5905);
     }
@@ -204,11 +203,9 @@ AAAAJ 59
 AAAAA 61
 2AAAA 23
 2JJJJ 53
-JJJJ2 41".split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        let p1 = solve_part_1(input.clone());
-        let p2 = solve_part_2(input);
+JJJJ2 41";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
         assert_eq!(p1, 6592);
         assert_eq!(p2, 6839);
     }
@@ -0,0 +1,375 @@
+use std::collections::HashMap;
+
+use crate::utils::{assumption::Assumption, checked::narrow, dot::Graph, math::synchronise, rng::Rng, parse::{self, Cursor, ParseError}};
+
+#[derive(Debug)]
+struct Element<'a> {
+    me: &'a str,
+    left: &'a str,
+    right: &'a str,
+    end_point: bool,
+}
+
+enum Instruction {
+    Left,
+    Right,
+}
+
+fn parse_instructions(line: Cursor) -> Result<Vec<Instruction>, ParseError> {
+    if line.is_empty() {
+        return Err(line.error("expected instructions"));
+    }
+    line.as_str().chars()
+     .enumerate()
+     .map(|(i, c)| match c {
+                'L' => Ok(Instruction::Left),
+                'R' => Ok(Instruction::Right),
+                _ => Err(line.error_at(i, "incorrect instruction")),
+     })
+    .collect()
+}
+
+fn parse_map<'a>(lines: &[Cursor<'a>]) -> Result<HashMap<&'a str, Element<'a>>, ParseError> {
+    let nodes = lines.iter()
+        .map(|l| {
+            let (key, values) = l.key_value("=")?;
+            let (_, values) = values.braced('(', ')')?;
+            let (left, right) = values.key_value(",")?;
+            Ok((key, left, right))
+        })
+        .collect::<Result<Vec<(Cursor, Cursor, Cursor)>, ParseError>>()?;
+    let map = nodes.iter()
+        .map(|(key, left, right)| {
+            (key.as_str(), Element{me: key.as_str(), left: left.as_str(), right: right.as_str(), end_point: key.as_str().ends_with('Z')})
+        }).collect::<HashMap<&str, Element>>();
+    for (_, left, right) in nodes.iter() {
+        for next in [left, right] {
+            if !map.contains_key(next.as_str()) {
+                return Err(next.error(format!("unknown node `{}`", next.as_str())));
+            }
+        }
+    }
+    Ok(map)
+}
+
+fn parse(input: &str) -> Result<(Vec<Instruction>, HashMap<&str, Element<'_>>), ParseError> {
+    match parse::sections(input).as_slice() {
+        [instructions, nodes] if instructions.len() == 1 => Ok((parse_instructions(instructions[0])?, parse_map(nodes)?)),
+        _ => Err(ParseError { line: 1, column: 1, message: "expected instructions and nodes separated by a blank line".to_string() }),
+    }
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i32, ParseError> {
+    let (instructions, map) = parse(input)?;
+
+    let mut current_element = "AAA";
+    let end_element = "ZZZ";
+    let mut step_counter = 0;
+
+    while current_element != end_element {
+        let instruction_id = step_counter % instructions.len();
+        match instructions[instruction_id] {
+            Instruction::Left => current_element = map.get(current_element).unwrap().left, 
+            Instruction::Right => current_element = map.get(current_element).unwrap().right, 
+        }
+        step_counter += 1;
+    }
+    Ok(narrow(step_counter))
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let (instructions, map) = parse(input)?;
+
+    let current_elements: Vec<&Element> = map.iter()
+        .filter(|(k, _)| k.ends_with('A'))
+        .map(|(_, v)| v)
+        .collect::<Vec<&Element>>();
+
+    // Brute force solution, takes too long ofc.
+    // let mut step_counter = 0;
+    // while current_elements.iter().any(|e| !e.end_point) {
+    //     current_elements = match instructions[step_counter % instructions.len()] {
+    //         Instruction::Left => current_elements.iter().map(|e2| map.get(e2.left).unwrap()).collect::<Vec<&Element>>(), 
+    //         Instruction::Right => current_elements.iter().map(|e2| map.get(e2.right).unwrap()).collect::<Vec<&Element>>(), 
+    //     };
+    //     step_counter += 1;
+    //     if step_counter % 1000000 == 0 {
+    //         println!("{step_counter}");
+    //     }
+    // }
+    // step_counter as i32
+
+    // This is probably not generic solution but it is what the AoC wants.
+    // The assumption is that each starting point ends only in one unique ending point (which was not
+    // explicitly written in the problem description). This significanly simplifies the problem. The
+    // other thing is that there exist a loop that any ending point arrives to only to the same ending
+    // point in a constant ammount of steps. The first arrival (offset) and the loop length (period)
+    // do not need to be equal, the ghosts are synchronised with the generalized CRT.
+    
+    let cycles = current_elements.iter()
+        .map(|e| {
+            let mut step_counter = 0;
+            let mut current_element = *e;
+            let mut offset = None;
+            loop {
+                current_element = match instructions[step_counter % instructions.len()] {
+                    Instruction::Left => map.get(current_element.left).unwrap(),
+                    Instruction::Right => map.get(current_element.right).unwrap(),
+                };
+                step_counter += 1;
+                if current_element.end_point {
+                    match offset {
+                        Some(o) => break (o as i64, (step_counter - o) as i64),
+                        None => offset = Some(step_counter),
+                    }
+                }
+            }
+        })
+        .collect::<Vec<(i64, i64)>>();
+    
+    Ok(narrow(synchronise(&cycles).expect("Ghosts never meet at the ending points!")))
+}
+
+// Walks from the start until the (node, instruction) state repeats. Returns the steps at which an
+// end node is reached within the first two rounds of the repetition, with the nodes reached, the
+// step where the repetition starts and its length.
+fn walk<'a>(start: &'a str, instructions: &[Instruction], map: &HashMap<&'a str, Element<'a>>, is_end: impl Fn(&Element) -> bool) -> (Vec<(usize, &'a str)>, usize, usize) {
+    let mut seen = HashMap::new();
+    let mut hits = vec![];
+    let mut current = map.get(start).unwrap();
+    let mut step = 0;
+    let (loop_start, loop_len) = loop {
+        if let Some(first) = seen.insert((current.me, step % instructions.len()), step) {
+            break (first, step - first);
+        }
+        current = match instructions[step % instructions.len()] {
+            Instruction::Left => map.get(current.left).unwrap(),
+            Instruction::Right => map.get(current.right).unwrap(),
+        };
+        step += 1;
+        if is_end(current) {
+            hits.push((step, current.me));
+        }
+    };
+    hits.retain(|(s, _)| *s < loop_start + loop_len);
+    let repeated = hits.iter()
+        .filter(|(s, _)| *s >= loop_start)
+        .map(|(s, node)| (s + loop_len, *node))
+        .collect::<Vec<(usize, &str)>>();
+    hits.extend(repeated);
+    (hits, loop_start, loop_len)
+}
+
+// Part 2 treats every ghost as the cycle (first end, steps to the next end), check that the ghosts
+// really behave like that
+pub fn validate(input: &str) -> Result<Vec<Assumption>, ParseError> {
+    let (instructions, map) = parse(input)?;
+    let mut assumptions = vec![];
+    if map.contains_key("AAA") {
+        let (hits, _, _) = walk("AAA", &instructions, &map, |e| e.me == "ZZZ");
+        assumptions.push(Assumption::new("AAA reaches ZZZ", !hits.is_empty()));
+    } else {
+        assumptions.push(Assumption::new("AAA exists", false));
+    }
+
+    let mut starts = map.keys().filter(|k| k.ends_with('A')).copied().collect::<Vec<&str>>();
+    starts.sort();
+    let mut cycles = vec![];
+    for start in starts {
+        let (hits, loop_start, loop_len) = walk(start, &instructions, &map, |e| e.end_point);
+        let mut ends = hits.iter().map(|(_, node)| *node).collect::<Vec<&str>>();
+        ends.sort();
+        ends.dedup();
+        assumptions.push(Assumption::new(format!("{} reaches exactly one end node (reaches {:?})", start, ends), ends.len() == 1));
+
+        let clean = match hits.as_slice() {
+            [(first, _), (second, _), ..] => {
+                let period = second - first;
+                loop_len % period == 0
+                    && hits.iter().enumerate().all(|(i, (s, _))| *s == first + i * period)
+                    && first + hits.len() * period >= loop_start + 2 * loop_len
+            },
+            _ => false,
+        };
+        assumptions.push(Assumption::new(format!("{} reaches its end in a clean cycle", start), clean));
+        if let [(first, _), (second, _), ..] = hits.as_slice() {
+            cycles.push((*first as i64, (second - first) as i64));
+        }
+    }
+    if assumptions.iter().all(|a| a.holds) {
+        assumptions.push(Assumption::new("the ghosts meet at the end nodes", synchronise(&cycles).is_some()));
+    }
+    Ok(assumptions)
+}
+
+// The node network, starts (`..A`) are houses and ends (`..Z`) double circles
+pub fn dot(input: &str) -> Result<Graph, ParseError> {
+    let (_, map) = parse(input)?;
+    let mut names = map.keys().copied().collect::<Vec<&str>>();
+    names.sort();
+
+    let mut graph = Graph::new();
+    for name in names.iter() {
+        let element = &map[name];
+        graph.node(name, if element.end_point { "doublecircle" } else if name.ends_with('A') { "invhouse" } else { "circle" });
+        if element.left == element.right {
+            graph.edge(name, element.left, Some("L/R".to_string()));
+        } else {
+            graph.edge(name, element.left, Some("L".to_string()));
+            graph.edge(name, element.right, Some("R".to_string()));
+        }
+    }
+    Ok(graph)
+}
+
+const MIDDLE: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+const GHOSTS: &[u8] = b"123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+
+// Every ghost walks its own path: a tail from its `..A` start into a loop holding its only `..Z`
+// node. Both directions lead to the same node, so the ghosts hit their ends in clean cycles and the
+// offsets are picked so that all of them meet. The first ghost starts at AAA and ends at ZZZ.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, MIDDLE.len() * MIDDLE.len() / 2);
+    let num_ghosts = 1 + rng.below(size.min(GHOSTS.len()).min(6));
+    let paths = (0..num_ghosts)
+        .map(|_| (1 + rng.below(size), 1 + rng.below(size)))
+        .collect::<Vec<(usize, usize)>>();
+    let meeting = paths.iter().map(|(tail, _)| *tail).max().unwrap() + rng.below(size * size);
+
+    let mut lines = vec![];
+    for (ghost, (tail, period)) in paths.iter().enumerate() {
+        let end = tail + (meeting - tail) % period;
+        let name = |i: usize| {
+            let prefix = if ghost == 0 { 'A' } else { GHOSTS[ghost - 1] as char };
+            if i == 0 {
+                format!("{}AA", prefix)
+            } else if i == end {
+                format!("{}ZZ", if ghost == 0 { 'Z' } else { prefix })
+            } else {
+                format!("{}{}{}", prefix, MIDDLE[i / MIDDLE.len()] as char, MIDDLE[i % MIDDLE.len()] as char)
+            }
+        };
+        for i in 0..tail + period {
+            let next = if i + 1 == tail + period { *tail } else { i + 1 };
+            lines.push(format!("{} = ({}, {})", name(i), name(next), name(next)));
+        }
+    }
+    rng.shuffle(&mut lines);
+
+    let instructions = (0..1 + rng.below(size))
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+    format!("{}\n\n{}\n", instructions, lines.join("\n"))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::property;
+
+    #[test]
+    fn simple_case() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+        let input2 = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input2).unwrap();
+        assert_eq!(p1, 2);
+        assert_eq!(p2, 6);
+    }
+
+    #[test]
+    fn simple_case_2() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let p1 = solve_part_1(input).unwrap();
+        assert_eq!(p1, 6);
+    }
+
+    #[test]
+    fn differential() {
+        // reference: the brute force walk of all ghosts at once
+        let brute_force = |input: &String| {
+            let (instructions, map) = parse(input).unwrap();
+            let mut current_elements = map.values()
+                .filter(|e| e.me.ends_with('A'))
+                .collect::<Vec<&Element>>();
+            let mut step_counter = 0;
+            while current_elements.iter().any(|e| !e.end_point) {
+                current_elements = current_elements.iter()
+                    .map(|e| match instructions[step_counter % instructions.len()] {
+                        Instruction::Left => map.get(e.left).unwrap(),
+                        Instruction::Right => map.get(e.right).unwrap(),
+                    })
+                    .collect();
+                step_counter += 1;
+            }
+            step_counter as u64
+        };
+        property::differential(200,
+            |rng| generate(rng, 8),
+            brute_force,
+            |input| solve_part_2(input).unwrap());
+    }
+
+    #[test]
+    fn assumptions() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        let assumptions = validate(input).unwrap();
+        assert_eq!(assumptions.iter().filter(|a| !a.holds).map(|a| a.description.as_str()).collect::<Vec<&str>>(), vec!["AAA exists"]);
+
+        // 11A passes 11Z and then loops through 12Z
+        let input = "L
+
+11A = (11Z, 11Z)
+11Z = (12Z, 12Z)
+12Z = (12Z, 12Z)";
+        let assumptions = validate(input).unwrap();
+        assert!(!assumptions[1].holds);
+
+        // 11Z is reached after 1, 2, 4, 5, 7, ... steps
+        let input = "LLR
+
+11A = (11Z, 11Z)
+11Z = (11Z, 11B)
+11B = (11Z, 11Z)";
+        let assumptions = validate(input).unwrap();
+        assert!(assumptions[1].holds);
+        assert!(!assumptions[2].holds);
+
+        let mut rng = Rng::new(1);
+        let input = generate(&mut rng, 8);
+        assert!(validate(&input).unwrap().iter().all(|a| a.holds));
+    }
+}
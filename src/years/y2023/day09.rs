@@ -0,0 +1,82 @@
+use crate::utils::{explain, rng::Rng, polynomial, parse::{self, ParseError}};
+
+fn parse_sequences(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let sequence = line.numbers::<i64>()?;
+            if sequence.is_empty() {
+                return Err(line.error("expected a sequence of numbers"));
+            }
+            Ok(sequence)
+        })
+        .collect()
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i64, ParseError> {
+    Ok(parse_sequences(input)?.into_iter()
+        .enumerate()
+        .map(|(i, sequence)| {
+            let last = *sequence.last().unwrap();
+            let next = predict_next_value(sequence);
+            if explain::enabled() {
+                explain::trace(1, format_args!("sequence {}", i + 1), format_args!("after {}", last), next);
+            }
+            next
+        })
+        .sum())
+}
+
+// Extends the sequence one step past its end
+fn predict_next_value(orig_data: Vec<i64>) -> i64 {
+    polynomial::extrapolate(&orig_data, orig_data.len() as i64)
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i64, ParseError> {
+    Ok(parse_sequences(input)?.into_iter()
+        .enumerate()
+        .map(|(i, sequence)| {
+            let first = sequence[0];
+            let previous = predict_previous_value(sequence);
+            if explain::enabled() {
+                explain::trace(2, format_args!("sequence {}", i + 1), format_args!("before {}", first), previous);
+            }
+            previous
+        })
+        .sum())
+}
+
+// Extends the sequence one step before its start
+fn predict_previous_value(orig_data: Vec<i64>) -> i64 {
+    polynomial::extrapolate(&orig_data, -1)
+}
+
+// `size` sequences sampled from integer polynomials of degree up to 5
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let coefficients = (0..1 + rng.below(6)).map(|_| rng.range(-5..6)).collect::<Vec<i64>>();
+            let start = rng.range(-10..11);
+            (start..start + 6 + rng.below(16) as i64)
+                .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * x + c).to_string())
+                .collect::<Vec<String>>()
+                .join(" ") + "\n"
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_case() {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 114);
+        assert_eq!(p2, 2);
+    }
+}
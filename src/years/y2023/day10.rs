@@ -0,0 +1,439 @@
+use crate::utils::{assumption::Assumption, checked::narrow, image::{self, Image}, polygon, rng::{self, Rng}, parse::{self, ParseError}};
+
+#[derive(Debug, Clone)]
+struct Location {
+    start: bool,
+    dist_from_start: i32,
+    conn: Vec<Connection>,
+    row: usize,
+    col: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Connection {
+    North,
+    South,
+    West,
+    East,
+}
+
+fn parse_map(input: &str) -> Result<(Vec<Vec<Location>>, [usize; 2]), ParseError>  {
+    let mut starts = vec![];
+    let tiles = parse::grid(input, |x, y, c| {
+        if c == 'S' {
+            starts.push([y, x]);
+        }
+        "|-LJ7F.S".contains(c).then_some(c)
+    })?;
+    if starts.len() != 1 {
+        return Err(ParseError { line: 1, column: 1, message: "expected exactly one starting tile `S`".to_string() });
+    }
+
+    let mut map = tiles.iter()
+        .enumerate()
+        .map(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .map(|(x, c)| Location { start: *c == 'S', dist_from_start: -1, conn: vec![], row: y, col: x})
+                .collect()
+        })
+        .collect::<Vec<Vec<Location>>>();
+
+    tiles.iter()
+        .enumerate()
+        .for_each(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .for_each(|(x, c)| {
+                    match c {
+                        '|' => add_connection(&mut map, y, x, &[Connection::North, Connection::South]), 
+                        '-' => add_connection(&mut map, y, x, &[Connection::West, Connection::East]), 
+                        'L' => add_connection(&mut map, y, x, &[Connection::North, Connection::East]), 
+                        'J' => add_connection(&mut map, y, x, &[Connection::North, Connection::West]), 
+                        '7' => add_connection(&mut map, y, x, &[Connection::South, Connection::West]), 
+                        'F' => add_connection(&mut map, y, x, &[Connection::South, Connection::East]), 
+                        '.' => add_connection(&mut map, y, x, &[]), 
+                        'S' => {
+                            add_connection(&mut map, y, x, &[]);
+                            map[y][x].start = true;
+                            map[y][x].dist_from_start = 0;
+                        },
+                        _ => unreachable!("Filtered out by the grid parser"),
+                    }
+                });
+        });
+    Ok((map, starts[0]))
+}
+
+fn add_connection(map: &mut [Vec<Location>], row: usize, col: usize, conn: &[Connection]) {
+    conn.iter()
+        .for_each(|c| {
+            match c {
+                Connection::North => {
+                    map[row][col].conn.push(Connection::North);
+                    if row > 0 {
+                        let r = &mut map[row-1];
+                        if r[col].start {
+                            r[col].conn.push(Connection::South);
+                        }
+                    }
+                },
+                Connection::South => {
+                    map[row][col].conn.push(Connection::South);
+                    if let Some(r) = map.get_mut(row+1) {
+                        if r[col].start {
+                            r[col].conn.push(Connection::North);
+                        }
+                    }
+                },
+                Connection::West => {
+                    map[row][col].conn.push(Connection::West);
+                    if col > 0 {
+                        let cl = &mut map[row][col-1]; 
+                        if cl.start {
+                            cl.conn.push(Connection::East);
+                        }
+                    }
+                },
+                Connection::East => {
+                    map[row][col].conn.push(Connection::East);
+                    if let Some(cl) = map[row].get_mut(col+1) {
+                        if cl.start {
+                            cl.conn.push(Connection::West);
+                        }
+                    }
+                },
+            };
+        });
+}
+
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i32, ParseError> {
+    let (mut map, start) = parse_map(input)?;
+    
+    // I encouter some issues with borrowing mut ref, so I just clone stuff here
+    // simple depth-first search with termination assumption of loop
+    let mut nodes = vec![start];
+    while let Some(loc) = nodes.pop() {
+        let current_node = map[loc[0]][loc[1]].clone();
+        for c in current_node.conn.iter() {
+            let next_node = match c {
+                    Connection::North => {
+                        if current_node.row > 0 {
+                            Some(&mut map[current_node.row-1][current_node.col])
+                        } else {
+                            None
+                        }
+                    },
+                    Connection::South => {
+                        if let Some(r) = map.get_mut(current_node.row+1) {
+                            Some(&mut r[current_node.col])
+                        } else {
+                            None
+                        }
+
+                    },
+                    Connection::West => {
+                        if current_node.col > 0 {
+                            Some(&mut map[current_node.row][current_node.col - 1])
+                        } else {
+                            None
+                        }
+                    }
+                    Connection::East => map[current_node.row].get_mut(current_node.col+1) ,
+                };
+            if let Some(node) = next_node {
+                if !node.start && ((node.dist_from_start < 0) | (node.dist_from_start > current_node.dist_from_start + 1)) {
+                    node.dist_from_start = current_node.dist_from_start + 1;
+                    nodes.push([node.row, node.col]);
+                }
+            }
+        }
+    }
+    Ok(map.iter()
+       .flatten()
+       .filter(|n| !n.start)
+       .map(|n| n.dist_from_start)
+       .max().unwrap_or(0))
+}
+
+// The tile next to `current` in the direction `dir` and the way back from it, when it is on the map
+// and connects back
+fn step(map: &[Vec<Location>], current: [usize; 2], dir: &Connection) -> Option<([usize; 2], Connection)> {
+    let (next, back) = match dir {
+        Connection::North if current[0] > 0 => ([current[0] - 1, current[1]], Connection::South),
+        Connection::South if current[0] + 1 < map.len() => ([current[0] + 1, current[1]], Connection::North),
+        Connection::West if current[1] > 0 => ([current[0], current[1] - 1], Connection::East),
+        Connection::East if current[1] + 1 < map[0].len() => ([current[0], current[1] + 1], Connection::West),
+        _ => return None,
+    };
+    map[next[0]][next[1]].conn.contains(&back).then_some((next, back))
+}
+
+// Walks the loop from the start and returns the visited tiles in order as (x, y), the tile where the
+// pipes stop leading on is an error
+fn trace_loop(map: &[Vec<Location>], start: [usize; 2]) -> Result<Vec<(i64, i64)>, ParseError> {
+    let mut path = vec![];
+    let mut current = start;
+    let mut came_from: Option<Connection> = None;
+    loop {
+        path.push((current[1] as i64, current[0] as i64));
+        let (next, back) = map[current[0]][current[1]].conn.iter()
+            .find(|c| came_from.as_ref() != Some(c))
+            .and_then(|dir| step(map, current, dir))
+            .ok_or_else(|| ParseError {
+                line: current[0] + 1,
+                column: current[1] + 1,
+                message: "the pipe loop through S is broken here".to_string(),
+            })?;
+        (current, came_from) = (next, Some(back));
+        if current == start {
+            break;
+        }
+    }
+    Ok(path)
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i32, ParseError> {
+    let (map, start) = parse_map(input)?;
+    let path = trace_loop(&map, start)?;
+
+    // the tile centers of the loop form a lattice polygon, enclosed tiles are its interior points
+    Ok(narrow(polygon::interior_points(&path)))
+}
+
+// The loop and the tiles it encloses (a tile is inside when a ray to its left crosses the loop an
+// odd number of times, counting the loop tiles connected to the north)
+fn enclosed_tiles(map: &[Vec<Location>], path: &[(i64, i64)]) -> Vec<Vec<bool>> {
+    let mut on_loop = vec![vec![false; map[0].len()]; map.len()];
+    path.iter().for_each(|(x, y)| on_loop[*y as usize][*x as usize] = true);
+    map.iter()
+        .enumerate()
+        .map(|(y, row)| {
+            let mut inside = false;
+            row.iter()
+                .enumerate()
+                .map(|(x, tile)| {
+                    if on_loop[y][x] {
+                        inside ^= tile.conn.contains(&Connection::North);
+                        false
+                    } else {
+                        inside
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn export(input: &str) -> Result<Vec<(&'static str, Image)>, ParseError> {
+    let (map, start) = parse_map(input)?;
+    let path = trace_loop(&map, start)?;
+    let enclosed = enclosed_tiles(&map, &path);
+    let mut image = Image::from_grid(&map, |x, y, tile| {
+        if enclosed[y][x] { image::GREEN } else if tile.conn.is_empty() { image::BLACK } else { image::GRAY }
+    });
+    path.iter().for_each(|(x, y)| image.set(*x as usize, *y as usize, image::YELLOW));
+    image.set(start[1], start[0], image::RED);
+    Ok(vec![("loop", image)])
+}
+
+// Both parts follow the pipes from S and expect to come back to it
+pub fn validate(input: &str) -> Result<Vec<Assumption>, ParseError> {
+    let (map, start) = parse_map(input)?;
+    let connections = map[start[0]][start[1]].conn.len();
+    let mut assumptions = vec![Assumption::new(format!("S connects to exactly two pipes (connects to {})", connections), connections == 2)];
+    if connections == 2 {
+        assumptions.push(Assumption::new("the pipes from S close a loop", trace_loop(&map, start).is_ok()));
+    }
+    Ok(assumptions)
+}
+
+// The loop is a random rectilinear polygon walked cell by cell, the other tiles are junk pipes.
+// The tiles around S which are not on the loop are ground, so only the loop connects to S.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let columns = 1 + rng.below(size.max(1));
+    let scale = (size / columns).max(1) as i64;
+    let mut cells = vec![(0_i64, 0_i64)];
+    for (dx, dy) in rng::random_rectilinear(rng, columns, scale) {
+        for _ in 0..dx.abs() + dy.abs() {
+            let (x, y) = cells[cells.len() - 1];
+            cells.push((x + dx.signum(), y + dy.signum()));
+        }
+    }
+    cells.pop();
+
+    let margin = rng.range(0..3);
+    let min_x = cells.iter().map(|c| c.0).min().unwrap() - margin;
+    let min_y = cells.iter().map(|c| c.1).min().unwrap() - margin;
+    let width = (cells.iter().map(|c| c.0).max().unwrap() - min_x + margin + 1) as usize;
+    let height = (cells.iter().map(|c| c.1).max().unwrap() - min_y + margin + 1) as usize;
+    let mut tiles = (0..height)
+        .map(|_| (0..width).map(|_| *rng.choose(&['.', '.', '.', '|', '-', 'L', 'J', '7', 'F'])).collect())
+        .collect::<Vec<Vec<char>>>();
+
+    let n = cells.len();
+    for i in 0..n {
+        let (x, y) = cells[i];
+        let towards = |(ox, oy): (i64, i64)| (ox - x, oy - y);
+        let tile = match (towards(cells[(i + n - 1) % n]), towards(cells[(i + 1) % n])) {
+            ((0, -1), (0, 1)) | ((0, 1), (0, -1)) => '|',
+            ((-1, 0), (1, 0)) | ((1, 0), (-1, 0)) => '-',
+            ((0, -1), (1, 0)) | ((1, 0), (0, -1)) => 'L',
+            ((0, -1), (-1, 0)) | ((-1, 0), (0, -1)) => 'J',
+            ((0, 1), (-1, 0)) | ((-1, 0), (0, 1)) => '7',
+            _ => 'F',
+        };
+        tiles[(y - min_y) as usize][(x - min_x) as usize] = tile;
+    }
+
+    let start = rng.below(n);
+    let (x, y) = cells[start];
+    tiles[(y - min_y) as usize][(x - min_x) as usize] = 'S';
+    for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+        let neighbour = (x + dx, y + dy);
+        let (col, row) = (neighbour.0 - min_x, neighbour.1 - min_y);
+        if col >= 0 && row >= 0 && (col as usize) < width && (row as usize) < height && !cells.contains(&neighbour) {
+            tiles[row as usize][col as usize] = '.';
+        }
+    }
+    tiles.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_case_p1() {
+        let input = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+        let p1 = solve_part_1(input).unwrap();
+        assert_eq!(p1, 4);
+    }
+
+    #[test]
+    fn simple_case_p1_2() {
+        let input = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+        let p1 = solve_part_1(input).unwrap();
+        assert_eq!(p1, 8);
+    }
+
+    #[test]
+    fn simple_case_p2() {
+        let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p2, 4);
+    }
+
+    #[test]
+    fn simple_case_p2_2() {
+        let input = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p2, 4);
+    }
+
+    #[test]
+    fn simple_case_p2_large() {
+        let input = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p2, 8);
+    }
+
+    #[test]
+    fn simple_case_p2_large2() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p2, 10);
+    }
+
+    #[test]
+    fn assumptions() {
+        let input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+        assert!(validate(input).unwrap().iter().all(|a| a.holds));
+
+        let input = "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...";
+        assert!(validate(input).unwrap().iter().all(|a| a.holds));
+
+        // S connects to three pipes, or its two pipes lead nowhere
+        let input = ".|.\n-S-\n...";
+        assert!(!validate(input).unwrap()[0].holds);
+        let input = "...\n-S-\n...";
+        assert!(!validate(input).unwrap()[1].holds);
+    }
+
+    #[test]
+    fn broken_loops() {
+        let error = solve_part_2("...\n.S.\n...\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        // the pipes right of S lead off the map, then into the ground
+        let error = solve_part_2("S-\n|.\n-.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+        assert!(solve_part_2("S7\n|.\n-.\n").is_err());
+    }
+
+    #[test]
+    fn export_enclosed() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        let (map, start) = parse_map(input).unwrap();
+        let enclosed = enclosed_tiles(&map, &trace_loop(&map, start).unwrap());
+        assert_eq!(enclosed.iter().flatten().filter(|e| **e).count(), 10);
+        let images = export(input).unwrap();
+        assert_eq!((images[0].1.width(), images[0].1.height()), (20, 10));
+    }
+}
@@ -1,43 +1,46 @@
-use std::{fs, collections::HashSet};
+use std::collections::HashSet;
 
-pub fn solve() {
-    let input = fs::read_to_string("./inputs/input_11.txt")
-        .expect("File not found")
-        .lines()
-        .map(|l: &str| l.to_string())
-        .collect::<Vec<String>>();
+use crate::utils::{config, rng::Rng, parse::{self, ParseError}};
 
-    let p1 = solve_part_2(input.clone(), 2);
-    let p2 = solve_part_2(input, 1000000);
+// How many times bigger the empty rows and columns get in the two parts, `[2023.day11] expansion`
+// in aoc.toml
+pub(crate) const EXPANSION: [i64; 2] = [2, 1000000];
 
-    println!("Part 1 solution = {}", p1); 
-    println!("Part 2 solution = {}", p2); 
+pub(crate) fn expansion() -> [i64; 2] {
+    config::param(2023, 11, "expansion", |v| v.as_int_array::<2>(), EXPANSION)
 }
 
-fn solve_part_2(input: Vec<String>, factor: i64) -> i64 {
+pub(crate) fn solve_part_2(input: &str, factor: i64) -> Result<i64, ParseError> {
+    let image = parse::grid(input, |_, _, c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let width = image.first().map(|row| row.len()).unwrap_or(0);
+
     let mut row_expantion_idx: HashSet<usize> = HashSet::<usize>::new();
-    (0..input.len()).for_each(|l| {
-        if input[l].chars().all(|c| c == '.') {
+    (0..image.len()).for_each(|l| {
+        if image[l].iter().all(|galaxy| !galaxy) {
             row_expantion_idx.insert(l);
         }
     });
     let mut col_expantion_idx: HashSet<usize> = HashSet::<usize>::new();
-    (0..input[0].len()).for_each(|cl| {
-        if input.iter().all(|l| (l.chars().nth(cl).unwrap() == '.')) {
+    (0..width).for_each(|cl| {
+        if image.iter().all(|l| !l[cl]) {
             col_expantion_idx.insert(cl);
         }
     });
 
     let mut galaxies: Vec<(i64, i64)> = vec![];
-    (0..input.len()).for_each(|y| {
-        (0..input[0].len()).for_each(|x| {
-            if input[y].chars().nth(x).unwrap() == '#' {
+    (0..image.len()).for_each(|y| {
+        (0..width).for_each(|x| {
+            if image[y][x] {
                 galaxies.push((y as i64, x as i64));
             }
         })
     });
 
-    galaxies.iter()
+    Ok(galaxies.iter()
         .enumerate()
         .map(|(i, g1)| {
             galaxies.iter()
@@ -56,9 +59,18 @@ fn solve_part_2(input: Vec<String>, factor: i64) -> i64 {
                     normal_rows + expanded_rows*factor + normal_cols + expanded_cols*factor
                 }).sum::<i64>()
         })
-        .sum()
+        .sum())
+}
+
+// Square image with about 5% of galaxies
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| (0..size).map(|_| if rng.chance(0.05) { '#' } else { '.' }).collect::<String>() + "\n")
+        .collect()
 }
 
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,14 +86,12 @@ mod tests {
 .........#
 ..........
 .......#..
-#...#.....".split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        let p1 = solve_part_2(input.clone(), 2);
+#...#.....";
+        let p1 = solve_part_2(input, 2).unwrap();
         assert_eq!(p1, 374);
-        let p2 = solve_part_2(input.clone(), 10);
+        let p2 = solve_part_2(input, 10).unwrap();
         assert_eq!(p2, 1030);
-        let p2 = solve_part_2(input, 100);
+        let p2 = solve_part_2(input, 100).unwrap();
         assert_eq!(p2, 8410);
     }
 }
@@ -1,36 +1,26 @@
-use std::{fs, collections::HashMap};
+use std::collections::HashMap;
 
-pub fn solve() {
-    let input = fs::read_to_string("./inputs/input_12.txt")
-        .expect("File not found")
-        .lines()
-        .map(|l: &str| l.to_string())
-        .collect::<Vec<String>>();
+use crate::utils::{parallel, rng::Rng, parse::{self, Cursor, ParseError}};
 
-    let p1 = solve_part_1(input.clone());
-    let p2 = solve_part_2(input);
-
-    println!("Part 1 solution = {}", p1); 
-    println!("Part 2 solution = {}", p2); 
-}
-
-fn solve_part_1(input: Vec<String>) -> i64 {
-    input.iter()
-        .map(|line| {
-            let (pattern, num_brokens) = parse_line(line);
-            possible_line_arrangements(pattern, &num_brokens)
-        })
-        .sum()
+pub(crate) fn solve_part_1(input: &str) -> Result<i64, ParseError> {
+    let rows = parse::lines(input)
+        .map(parse_line)
+        .collect::<Result<Vec<(&str, Vec<i64>)>, ParseError>>()?;
+    Ok(parallel::map(&rows, |(pattern, num_brokens)| possible_line_arrangements(pattern, num_brokens))
+        .iter()
+        .sum())
 }
 
-fn parse_line(line: &str) -> (&str, Vec<i64>) {
-    let mut line_iter = line.split_whitespace();
-    let pattern = line_iter.next().unwrap();
-    let num_brokens: Vec<i64>  = line_iter.next().unwrap()
-        .split(',')
-        .map(|n| n.parse::<i64>().unwrap())
-        .collect();
-    (pattern, num_brokens)
+fn parse_line(line: Cursor<'_>) -> Result<(&str, Vec<i64>), ParseError> {
+    let (pattern, groups) = line.trim().split_once(" ")?;
+    if let Some(i) = pattern.as_str().chars().position(|c| !".#?".contains(c)) {
+        return Err(pattern.error_at(i, "expected `.`, `#` or `?`"));
+    }
+    let num_brokens: Vec<i64>  = groups.numbers_sep::<i64>(",")?;
+    if num_brokens.iter().any(|n| *n <= 0) {
+        return Err(groups.error("group sizes should be positive"));
+    }
+    Ok((pattern.as_str(), num_brokens))
 }
 
 fn possible_line_arrangements(pattern: &str, num_brokens: &[i64]) -> i64 {
@@ -145,38 +135,53 @@ fn first_wildcard_match(str_wild: &str, pattern: &str) -> Option<usize> {
     }
 }
 
-fn parse_line_augmented(line: &str) -> (String, Vec<i64>) {
-    let mut line_iter = line.split_whitespace();
-    let pattern_orig = line_iter.next().unwrap();
+fn parse_line_augmented(line: Cursor) -> Result<(String, Vec<i64>), ParseError> {
+    let (pattern_orig, num_brokens_orig) = parse_line(line)?;
     let mut pattern = pattern_orig.to_string();
 
-    let num_brokens_orig: Vec<i64>  = line_iter.next().unwrap()
-        .split(',')
-        .map(|n| n.parse::<i64>().unwrap())
-        .collect();
-
     let mut num_brokens: Vec<i64>  = num_brokens_orig.clone();
     for _ in 0..4{
         pattern.push('?');
         pattern += pattern_orig;
         num_brokens_orig.iter().for_each(|i| num_brokens.push(*i));
     }
-    (pattern, num_brokens)
+    Ok((pattern, num_brokens))
 }
 
-fn solve_part_2(input: Vec<String>) -> i64 {
-    input.iter()
-        .map(|line| {
-            let (pattern, num_brokens) = parse_line_augmented(line);
-            possible_line_arrangements(&pattern, &num_brokens)
+pub(crate) fn solve_part_2(input: &str) -> Result<i64, ParseError> {
+    let rows = parse::lines(input)
+        .map(parse_line_augmented)
+        .collect::<Result<Vec<(String, Vec<i64>)>, ParseError>>()?;
+    Ok(parallel::map(&rows, |(pattern, num_brokens)| possible_line_arrangements(pattern, num_brokens))
+        .iter()
+        .sum())
+}
+
+// Rows of up to 20 springs, every row is made from a real arrangement so it has at least one
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let len = 1 + rng.below(20.min(size.max(1)));
+            let mut springs = (0..len)
+                .map(|_| if rng.chance(0.4) { '#' } else { '.' })
+                .collect::<Vec<char>>();
+            springs[rng.below(len)] = '#';
+            let groups = springs.split(|c| *c == '.')
+                .filter(|g| !g.is_empty())
+                .map(|g| g.len().to_string())
+                .collect::<Vec<String>>();
+            springs.iter_mut().for_each(|c| if rng.chance(0.5) { *c = '?' });
+            format!("{} {}\n", springs.iter().collect::<String>(), groups.join(","))
         })
-        .sum()
+        .collect()
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::bench;
+    use crate::utils::property;
 
     #[test]
     fn simple_case() {
@@ -192,23 +197,63 @@ mod tests {
 ????.??#?.?.????# 3,4,1,1
 ??###?##.??????#??#. 8,1,2,2
 ?#?#??????#?? 4,1,1
-??#?##???#?? 1,3,3".split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
+??#?##???#?? 1,3,3";
 
         let input_orig = "???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
-?###???????? 3,2,1".split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
+?###???????? 3,2,1";
 
 
-        let p1 = solve_part_1(input.clone());
-        let p2 = solve_part_2(input_orig);
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input_orig).unwrap();
         assert_eq!(p1, 61);
         assert_eq!(p2, 525152);
     }
+
+    #[test]
+    fn differential() {
+        // reference: try every assignment of the unknown springs
+        let brute_force = |input: &String| {
+            input.lines()
+                .map(|line| {
+                    let (pattern, num_brokens) = parse_line(Cursor::new(1, line)).unwrap();
+                    let unknown = pattern.chars().filter(|c| *c == '?').count();
+                    (0..1_u32 << unknown)
+                        .filter(|mask| {
+                            let mut bit = 0;
+                            let springs = pattern.chars()
+                                .map(|c| if c != '?' { c } else {
+                                    bit += 1;
+                                    if mask & (1 << (bit - 1)) != 0 { '#' } else { '.' }
+                                })
+                                .collect::<String>();
+                            springs.split('.').filter(|g| !g.is_empty()).map(|g| g.len() as i64).eq(num_brokens.iter().copied())
+                        })
+                        .count() as i64
+                })
+                .sum::<i64>()
+        };
+        property::differential(200,
+            |rng| generate(rng, 12),
+            brute_force,
+            |input| solve_part_1(input).unwrap());
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_recursive_match() {
+        let rows = ["???.### 1,1,3", ".??..??...?##. 1,1,3", "?#?#?#?#?#?#?#? 1,3,1,6",
+                    "????.#...#... 4,1,1", "????.######..#####. 1,6,5", "?###???????? 3,2,1"]
+            .iter()
+            .map(|line| parse_line_augmented(Cursor::new(1, line)).unwrap())
+            .collect::<Vec<(String, Vec<i64>)>>();
+        bench::bench("day12 recursive_match", || {
+            rows.iter()
+                .map(|(pattern, num_brokens)| possible_line_arrangements(pattern, num_brokens))
+                .sum::<i64>()
+        });
+    }
 }
@@ -0,0 +1,217 @@
+use crate::utils::{rng::Rng, parse::{self, ParseError}};
+
+// Patterns are converted to binary strings ('.' -> 0, '#' -> 1), each row then fits into an u32
+fn get_maps(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+    parse::sections(input).iter()
+        .map(|section| {
+            let width = section[0].len();
+            section.iter()
+                .map(|line| {
+                    if line.len() != width || width > 32 {
+                        return Err(line.error(format!("expected a row of {} cells (at most 32)", width)));
+                    }
+                    line.as_str().chars()
+                        .enumerate()
+                        .map(|(i, c)| match c {
+                            '.' => Ok('0'),
+                            '#' => Ok('1'),
+                            _ => Err(line.error_at(i, "expected `.` or `#`")),
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i32, ParseError> {
+    let maps = get_maps(input)?;
+    Ok(maps.iter()
+        .fold(0, |acc, map| {
+            let vert_refl = get_reflection_vert(map, false).unwrap_or(0); 
+            let horiz_refl = get_reflection_horiz(map, false).unwrap_or(0); 
+            if vert_refl > horiz_refl {
+                acc + vert_refl
+            } else {
+                acc + 100*horiz_refl
+            }
+        }))
+}
+
+fn is_symetric(map: &[u32], line_id: f32, smudge: bool) -> Option<i32> {
+    let mut span = 0.0;
+    let mut smudge_num = 0;
+    loop {
+        let left = (line_id - span).floor() as i32;
+        let right = (line_id + span).ceil() as usize;
+        if smudge {
+            if left < 0 || right > map.len()-1 {
+                break;
+            }
+           
+            let diff_str = format!("{:b}", map[left as usize] ^ map[right]);
+            let diff = diff_str.chars().filter(|c| *c == '1').count();
+            if diff > 1 || (diff == 1 && smudge_num > 0) {
+                break;
+            }
+            
+            if diff > 0 {
+                smudge_num += 1;
+            }
+        } else {
+            if left < 0 || right > map.len()-1 || map[left as usize] != map[right] {
+                break;
+            }
+        }
+        span += 1.0; 
+    }
+    
+    if span < 0.5 || (smudge_num == 0 && smudge) {
+        None
+    } else {
+        Some(span as i32)
+    }
+}
+
+fn convert_map(map: &[String]) -> Vec<u32> {
+    map.iter()
+        .map(|s| u32::from_str_radix(s, 2).expect("Not a binary number!"))
+        .collect()
+}
+
+fn find_max_symetric(map: Vec<u32>, smudge: bool) -> Option<i32> {
+    let mut max_index = 0;
+    (0..map.len()-1).for_each(|i| {
+        if let Some(x) = is_symetric(&map, i as f32 + 0.5, smudge) {
+            if (i as i32 +1) > max_index && (((i + 1) as i32 -x) == 0 || ((i + 1) + x as usize) == map.len())  {
+                max_index = (i + 1) as i32;
+            }
+        }
+    });
+    if max_index > 0 {
+        Some(max_index)
+    } else {
+        None
+    }
+}
+
+fn get_reflection_vert(map: &[String], smudge: bool) -> Option<i32> {
+    // Transpose
+    let map_norm = &(0..map[0].len()).map(|col| {
+            (0..map.len()).fold("".to_string(), |acc, row| {acc + &map[row].chars().nth(col).unwrap().to_string()})
+          }).collect::<Vec<String>>();
+    let map_converted = convert_map(map_norm);
+    find_max_symetric(map_converted, smudge)
+}
+
+fn get_reflection_horiz(map: &[String], smudge: bool) -> Option<i32> {
+    let map_converted = convert_map(map);
+    find_max_symetric(map_converted, smudge)
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i32, ParseError> {
+    let maps = get_maps(input)?;
+    Ok(maps.iter()
+        .fold(0, |acc, map| {
+            let vert_refl = get_reflection_vert(map, true).unwrap_or(0); 
+            let horiz_refl = get_reflection_horiz(map, true).unwrap_or(0); 
+            if vert_refl > horiz_refl {
+                acc + vert_refl
+            } else {
+                acc + 100*horiz_refl
+            }
+        }))
+}
+
+// Number of differing cells when the pattern is folded between rows `line` and `line + 1`
+fn fold_mismatches(rows: &[u32], line: usize) -> u32 {
+    (0..=line)
+        .rev()
+        .zip(line + 1..rows.len())
+        .map(|(a, b)| (rows[a] ^ rows[b]).count_ones())
+        .sum()
+}
+
+fn transpose(map: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..map[0].len()).map(|x| map.iter().map(|row| row[x]).collect()).collect()
+}
+
+// A clean mirror between columns and a smudged one between rows: the pattern is made symmetric
+// about both and a cell outside the column mirror is flipped. Patterns with any other (smudged)
+// mirror are thrown away, half of the patterns are transposed.
+fn generate_pattern(rng: &mut Rng, max_side: usize) -> Vec<Vec<bool>> {
+    loop {
+        let width = 5 + rng.below(max_side - 4);
+        let height = 5 + rng.below(max_side - 4);
+        let column = 1 + rng.below((width - 1) / 2);
+        let row = 1 + rng.below(height - 1);
+        let mut map = (0..height)
+            .map(|_| (0..width).map(|_| rng.chance(0.5)).collect())
+            .collect::<Vec<Vec<bool>>>();
+        for y in 0..row.min(height - row) {
+            map[row + y] = map[row - 1 - y].clone();
+        }
+        for line in map.iter_mut() {
+            (0..column).for_each(|x| line[column + x] = line[column - 1 - x]);
+        }
+        let y = row - 1 - rng.below(row.min(height - row));
+        let x = 2 * column + rng.below(width - 2 * column);
+        map[y][x] = !map[y][x];
+        if rng.chance(0.5) {
+            map = transpose(&map);
+        }
+
+        let mismatches = [map.clone(), transpose(&map)].iter()
+            .flat_map(|m| {
+                let rows = m.iter()
+                    .map(|r| r.iter().fold(0, |acc, c| acc << 1 | *c as u32))
+                    .collect::<Vec<u32>>();
+                (0..rows.len() - 1).map(move |line| fold_mismatches(&rows, line))
+            })
+            .collect::<Vec<u32>>();
+        if mismatches.iter().filter(|m| **m == 0).count() == 1 && mismatches.iter().filter(|m| **m == 1).count() == 1 {
+            return map;
+        }
+    }
+}
+
+// `size` patterns of 5 to 5 + size cells per side (at most 32)
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            generate_pattern(rng, (5 + size).min(32)).iter()
+                .map(|row| row.iter().map(|c| if *c { '#' } else { '.' }).collect::<String>() + "\n")
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_case() {
+        let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 405);
+        assert_eq!(p2, 400);
+    }
+}
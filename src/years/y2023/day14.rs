@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+
+use crate::utils::{assumption::Assumption, image::{self, Image}, rng::Rng, parse::{self, ParseError}, visualize::{self, Cell, Color, Render}};
+
+// Spin cycles of part 2
+const SPINS: usize = 1000000000;
+// Spin cycles the validator runs to find a repeated platform
+const SPIN_LIMIT: usize = 10000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum LocType {
+    Empty, 
+    Rounded,
+    Cube,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Loc{
+    inside: LocType,
+    row: usize,
+    col: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum TiltType {
+    North,
+    West,
+    South,
+    East,
+}
+
+impl Render for [Vec<Loc>] {
+    fn width(&self) -> usize {
+        self.first().map_or(0, |row| row.len())
+    }
+
+    fn height(&self) -> usize {
+        self.len()
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        match self[y][x].inside {
+            LocType::Empty => Cell::plain('.'),
+            LocType::Rounded => Cell::colored('O', Color::Yellow),
+            LocType::Cube => Cell::colored('#', Color::Gray),
+        }
+    }
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i32, ParseError> {
+    let mut platform: Vec<Vec<Loc>> = parse_platform(input)?;  
+    visualize::frame("platform", platform.as_slice());
+    tilt_platform(&mut platform, &TiltType::North);
+    visualize::frame("tilted North", platform.as_slice());
+    visualize::finish();
+    Ok(compute_total_beam_load(&platform, TiltType::North))
+}
+
+fn compute_total_beam_load(platform: &[Vec<Loc>], tilt_dir: TiltType) -> i32 {
+    match tilt_dir {
+        TiltType::North => {
+            platform.iter()
+                .enumerate()
+                .fold(0, |acc, (y, row)| {
+                    let count = row.iter()
+                        .filter(|l| l.inside == LocType::Rounded)
+                        .count();
+                    acc + ((platform.len() - y) * count) as i32 
+                })
+        },
+        _ => panic!("Not Implemented"),
+    }
+}
+
+fn fill_interval(platform: &mut [Vec<Loc>], tilt_dir: &TiltType, interval_start: usize, interval_end: usize, num_to_fill: usize, rowcol: usize) {
+    match tilt_dir {
+        TiltType::North => {
+            (interval_start..interval_start+num_to_fill).for_each(|y| platform[y][rowcol].inside = LocType::Rounded);
+            (interval_start+num_to_fill..interval_end).for_each(|y| platform[y][rowcol].inside = LocType::Empty);
+        },
+        TiltType::South => {
+            (interval_start..interval_end-num_to_fill).for_each(|y| platform[y][rowcol].inside = LocType::Empty);
+            (interval_end-num_to_fill..interval_end).for_each(|y| platform[y][rowcol].inside = LocType::Rounded);
+        },
+        TiltType::West => {
+            (interval_start..interval_start+num_to_fill).for_each(|x| platform[rowcol][x].inside = LocType::Rounded);
+            (interval_start+num_to_fill..interval_end).for_each(|x| platform[rowcol][x].inside = LocType::Empty);
+        },
+        TiltType::East => {
+            (interval_start..interval_end-num_to_fill).for_each(|x| platform[rowcol][x].inside = LocType::Empty);
+            (interval_end-num_to_fill..interval_end).for_each(|x| platform[rowcol][x].inside = LocType::Rounded);
+        },
+    };
+}
+
+fn tilt_platform(platform: &mut [Vec<Loc>], tilt_dir: &TiltType) {
+    match tilt_dir {
+        TiltType::North | TiltType::South => {
+            (0..platform[0].len()).for_each(|x| {
+                let blocks: Vec<usize> = (0..platform.len()).filter(|y| platform[*y][x].inside == LocType::Cube).collect();
+
+                if blocks.is_empty() {
+                    let num_to_fill = (0..platform.len()).filter(|y| platform[*y][x].inside == LocType::Rounded).count();
+                    fill_interval(platform, tilt_dir, 0, platform.len(), num_to_fill, x);
+                } else {
+                    let interval_start = 0;
+                    let interval_end = blocks[0];
+                    let num_to_fill = (interval_start..interval_end).filter(|y| platform[*y][x].inside == LocType::Rounded).count();
+                    fill_interval(platform, tilt_dir, interval_start, interval_end, num_to_fill, x);
+
+                    for i in 0..blocks.len()-1 {
+                        let interval_start= blocks[i] + 1;
+                        let interval_end = blocks[i+1];
+                        let num_to_fill = (interval_start..interval_end).filter(|y| platform[*y][x].inside == LocType::Rounded).count();
+                        fill_interval(platform, tilt_dir, interval_start, interval_end, num_to_fill, x);
+                    }
+
+                    let interval_start= blocks[blocks.len()-1]+1;
+                    let interval_end = platform.len();
+                    let num_to_fill = (interval_start..interval_end).filter(|y| platform[*y][x].inside == LocType::Rounded).count();
+                    fill_interval(platform, tilt_dir, interval_start, interval_end, num_to_fill, x);
+                }
+            });
+        },
+        TiltType::West | TiltType::East => {
+            (0..platform.len()).for_each(|y| {
+                let blocks: Vec<usize> = (0..platform[0].len()).filter(|x| platform[y][*x].inside == LocType::Cube).collect();
+
+                if blocks.is_empty() {
+                    let num_to_fill = (0..platform[0].len()).filter(|x| platform[y][*x].inside == LocType::Rounded).count();
+                    fill_interval(platform, tilt_dir, 0, platform[0].len(), num_to_fill, y);
+                } else {
+                    let interval_start = 0;
+                    let interval_end = blocks[0];
+                    let num_to_fill = (interval_start..interval_end).filter(|x| platform[y][*x].inside == LocType::Rounded).count();
+                    fill_interval(platform, tilt_dir, interval_start, interval_end, num_to_fill, y);
+
+                    for i in 0..blocks.len()-1 {
+                        let interval_start= blocks[i] + 1;
+                        let interval_end = blocks[i+1];
+                        let num_to_fill = (interval_start..interval_end).filter(|x| platform[y][*x].inside == LocType::Rounded).count();
+                        fill_interval(platform, tilt_dir, interval_start, interval_end, num_to_fill, y);
+                    }
+
+                    let interval_start= blocks[blocks.len()-1]+1;
+                    let interval_end = platform[0].len();
+                    let num_to_fill = (interval_start..interval_end).filter(|x| platform[y][*x].inside == LocType::Rounded).count();
+                    fill_interval(platform, tilt_dir, interval_start, interval_end, num_to_fill, y);
+                }
+            });
+        },
+    }
+}
+
+fn parse_platform(input: &str) -> Result<Vec<Vec<Loc>>, ParseError> {
+    parse::grid(input, |x, y, c| {
+        let inside = match c {
+            'O' => LocType::Rounded,
+            '#' => LocType::Cube,
+            '.' => LocType::Empty,
+            _ => return None,
+        };
+        Some(Loc { inside, row: y, col: x })
+    })
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i32, ParseError> {
+    let mut platform: Vec<Vec<Loc>> = parse_platform(input)?;  
+    let cycle = [TiltType::North, TiltType::West, TiltType::South, TiltType::East];
+    let total_iter = SPINS;
+    let mut support = vec![];
+    let mut answers: HashMap<usize, i32> = HashMap::new();
+    let rounded = |platform: &[Vec<Loc>]| platform.iter().flatten().map(|l| l.inside == LocType::Rounded).collect::<Vec<bool>>();
+    for i in 0..total_iter {
+        let before = rounded(&platform);
+        cycle.iter()
+            .for_each(|cycle_type| { 
+                tilt_platform(&mut platform, cycle_type);
+                if visualize::enabled() {
+                    visualize::frame(&format!("cycle {}, tilted {:?}", i + 1, cycle_type), platform.as_slice());
+                }
+            });
+        support.push(compute_total_beam_load(&platform, TiltType::North));
+
+        // the rocks came to rest, the cycle detection below needs cycles of at least two
+        if rounded(&platform) == before {
+            break;
+        }
+
+        // detect cycle
+        if support.len() > 6 {
+            if let Some(x_) = support[..support.len()-1].iter().rposition(|x| *x == *support.iter().last().unwrap()) {
+                let x = x_ + 1;
+                let pattern_len = support.len() - x;
+                if pattern_len > 1 && support.len()> 2*pattern_len && support[x-pattern_len..x] == support[x..] {
+                    let answer = support[x + (total_iter-x-1) % pattern_len];
+                    if let Some(x) = answers.insert(pattern_len, answer) {
+                        if x == answer && pattern_len >= *answers.keys().max().unwrap()  {
+                            support.push(answer);
+                            break;
+                        }
+                    };
+                }
+            }
+        }
+    }
+    visualize::finish();
+    Ok(*support.last().unwrap())
+}
+
+// Part 2 finds the period of the spin cycles from the north loads alone. Running the cycles until
+// a platform repeats gives the load after all the cycles without that shortcut.
+pub fn validate(input: &str) -> Result<Vec<Assumption>, ParseError> {
+    let mut platform = parse_platform(input)?;
+    let mut seen = HashMap::<Vec<Vec<Loc>>, usize>::new();
+    let mut loads = vec![];
+    let mut repeat = None;
+    for i in 0..=SPIN_LIMIT {
+        if let Some(first) = seen.insert(platform.clone(), i) {
+            repeat = Some((first, i - first));
+            break;
+        }
+        loads.push(compute_total_beam_load(&platform, TiltType::North));
+        for tilt_dir in [TiltType::North, TiltType::West, TiltType::South, TiltType::East] {
+            tilt_platform(&mut platform, &tilt_dir);
+        }
+    }
+    let Some((start, period)) = repeat else {
+        return Ok(vec![Assumption::new(format!("a platform repeats within {} spin cycles", SPIN_LIMIT), false)]);
+    };
+    let load = loads[start + (SPINS - start) % period];
+    let answer = solve_part_2(input)?;
+    Ok(vec![
+        Assumption::new(format!("a platform repeats within {} spin cycles (every {} from cycle {})", SPIN_LIMIT, period, start), true),
+        Assumption::new(format!("the period of the loads gives the load of the repeating platforms ({}, got {})", load, answer), load == answer),
+    ])
+}
+
+// The platform tilted north as in part 1
+pub fn export(input: &str) -> Result<Vec<(&'static str, Image)>, ParseError> {
+    let mut platform = parse_platform(input)?;
+    tilt_platform(&mut platform, &TiltType::North);
+    Ok(vec![("tilted_north", Image::from_grid(&platform, |_, _, l| match l.inside {
+        LocType::Empty => image::BLACK,
+        LocType::Rounded => image::YELLOW,
+        LocType::Cube => image::GRAY,
+    }))])
+}
+
+// Platform of `size` rows between half and twice as wide, with 20% of round and 15% of cube-shaped
+// rocks
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let width = (size / 2).max(1) + rng.below(size + size / 2 + 1);
+    (0..size)
+        .map(|_| {
+            (0..width)
+                .map(|_| match rng.below(20) {
+                    0..=3 => 'O',
+                    4..=6 => '#',
+                    _ => '.',
+                })
+                .collect::<String>() + "\n"
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{bench, rng::Rng, snapshot};
+
+    #[test]
+    fn simple_case() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 136);
+        assert_eq!(p2, 64);
+    }
+
+    #[test]
+    fn platforms_at_rest() {
+        // the loads never change, the cycle detection alone would spin the 10^9 cycles
+        assert_eq!(solve_part_2("#..\n.#.\n").unwrap(), 0);
+        assert_eq!(solve_part_2("#O#\n###\n").unwrap(), 2);
+    }
+
+    #[test]
+    fn rectangular_platforms() {
+        let mut platform = parse_platform("O.#.O.\n......\n").unwrap();
+        for tilt_dir in [TiltType::North, TiltType::West, TiltType::South, TiltType::East] {
+            tilt_platform(&mut platform, &tilt_dir);
+        }
+        assert_eq!(visualize::render(platform.as_slice(), false), "..#...\n....OO\n");
+        assert_eq!(solve_part_1("O.\n..\n.#\n").unwrap(), 3);
+        assert_eq!(solve_part_2("O.\n..\n.#\n").unwrap(), 1);
+    }
+
+    #[test]
+    fn loads_period() {
+        let input = "O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....";
+        assert!(validate(input).unwrap().iter().all(|a| a.holds));
+        assert!(validate("#O#\n###\n").unwrap().iter().all(|a| a.holds));
+    }
+
+    #[test]
+    fn spin_snapshot() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let mut platform = parse_platform(input).unwrap();
+        let mut frames = String::new();
+        for tilt_dir in [TiltType::North, TiltType::West, TiltType::South, TiltType::East] {
+            tilt_platform(&mut platform, &tilt_dir);
+            frames += &format!("tilted {:?}\n{}", tilt_dir, visualize::render(platform.as_slice(), false));
+        }
+        snapshot::assert_snapshot("2023/day14_one_spin", &frames);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_tilt_platform() {
+        let mut rng = Rng::new(14);
+        let input = (0..100)
+            .map(|_| (0..100).map(|_| *rng.choose(&['O', 'O', '#', '.', '.', '.', '.'])).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+        let platform = parse_platform(&input).unwrap();
+        bench::bench_with_setup("day14 tilt_platform (spin cycle)", || platform.clone(), |mut platform| {
+            for tilt_dir in [TiltType::North, TiltType::West, TiltType::South, TiltType::East] {
+                tilt_platform(&mut platform, &tilt_dir);
+            }
+            platform
+        });
+    }
+}
@@ -0,0 +1,51 @@
+use crate::utils::{rng::Rng, holiday_map::{self, HolidayMap}, parse::{Cursor, ParseError}};
+
+pub(crate) fn solve_part_1(input: &str) -> u32 {
+    input.split(',')
+        .map(|step| holiday_map::hash(step.as_bytes()) as u32)
+        .sum()
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let mut boxes = HolidayMap::<&str, u32>::new();
+    for cmd in Cursor::new(1, input).split(",") {
+        if let Ok((label, focal_length)) = cmd.split_once("=") {
+            boxes.insert(label.as_str(), focal_length.parse::<u32>()?);
+        } else if let Some(label) = cmd.strip_suffix("-") {
+            boxes.remove(label.as_str());
+        } else {
+            return Err(cmd.error("invalid command, expected `label=N` or `label-`"));
+        }
+    }
+
+    Ok(boxes.focusing_power())
+}
+
+// 4 * size steps on a handful of labels, so the lenses get replaced and removed
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let labels = (0..1 + size.max(1) / 2)
+        .map(|_| (0..1 + rng.below(4)).map(|_| (b'a' + rng.below(26) as u8) as char).collect())
+        .collect::<Vec<String>>();
+    let steps = (0..4 * size.max(1))
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.chance(0.3) { format!("{}-", label) } else { format!("{}={}", label, 1 + rng.below(9)) }
+        })
+        .collect::<Vec<String>>();
+    steps.join(",") + "\n"
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_case() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".to_string();
+        let p1 = solve_part_1(&input);
+        let p2 = solve_part_2(&input).unwrap();
+        assert_eq!(p1, 1320);
+        assert_eq!(p2, 145);
+    }
+}
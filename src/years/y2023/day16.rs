@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use crate::utils::{checked::narrow, image::{self, Image}, parallel, rng::Rng, parse::{self, ParseError}, visualize::{self, Cell, Color, Render}};
+
+enum NodeType {
+    Empty,
+    MirrorSlash,
+    MirrorBackSlash,
+    SplitterPipe,
+    SplitterDash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Pos {
+    x: i32,
+    y: i32,
+}
+
+impl Pos {
+    fn move_in_dir(&self, dir: &Direction, map: &[Vec<NodeType>]) -> Option<Pos> {
+        match dir {
+            Direction::North => if self.y > 0 {Some(Pos {x: self.x, y: self.y - 1})} else {None},
+            Direction::South => if (self.y as usize) < map.len()-1 {Some(Pos {x: self.x, y: self.y + 1})} else {None},
+            Direction::West  => if self.x > 0 {Some(Pos {x: self.x - 1, y: self.y})} else {None},
+            Direction::East  => if (self.x as usize) < map[0].len()-1 {Some(Pos {x: self.x + 1, y: self.y})} else {None},
+        }
+    }
+    fn move_on_map(&self, dir: &Direction, map: &[Vec<NodeType>]) -> Vec<(Direction, Option<Pos>)> {
+        match map[self.y as usize][self.x as usize] {
+            NodeType::Empty => vec![(dir.clone(), self.move_in_dir(dir, map))],
+            NodeType::MirrorSlash => {
+                match dir {
+                    Direction::South => vec![(Direction::West, self.move_in_dir(&Direction::West, map))],
+                    Direction::North => vec![(Direction::East, self.move_in_dir(&Direction::East, map))],
+                    Direction::East  => vec![(Direction::North, self.move_in_dir(&Direction::North, map))],
+                    Direction::West  => vec![(Direction::South, self.move_in_dir(&Direction::South, map))],
+                }
+            },
+            NodeType::MirrorBackSlash => {
+                match dir {
+                    Direction::South => vec![(Direction::East, self.move_in_dir(&Direction::East, map))],
+                    Direction::North => vec![(Direction::West, self.move_in_dir(&Direction::West, map))],
+                    Direction::East  => vec![(Direction::South, self.move_in_dir(&Direction::South, map))],
+                    Direction::West  => vec![(Direction::North, self.move_in_dir(&Direction::North, map))],
+                }
+            },
+            NodeType::SplitterPipe => {
+                match dir {
+                    Direction::North | Direction::South => vec![(dir.clone(), self.move_in_dir(dir, map))],
+                    Direction::West | Direction::East  => vec![(Direction::North, self.move_in_dir(&Direction::North, map)), 
+                                                               (Direction::South, self.move_in_dir(&Direction::South, map))],
+                }
+            },
+            NodeType::SplitterDash => {
+                match dir {
+                   Direction::West | Direction::East => vec![(dir.clone(), self.move_in_dir(dir, map))],
+                   Direction::North | Direction::South => vec![(Direction::East, self.move_in_dir(&Direction::East, map)), 
+                                                               (Direction::West, self.move_in_dir(&Direction::West, map))],
+                }
+            },
+        }
+    }
+}
+
+
+// The contraption with the energized tiles and the heads of the beams
+struct Beams<'a> {
+    map: &'a [Vec<NodeType>],
+    visited: &'a [Vec<i32>],
+    heads: &'a [(Direction, Pos)],
+}
+
+impl Render for Beams<'_> {
+    fn width(&self) -> usize {
+        self.map[0].len()
+    }
+
+    fn height(&self) -> usize {
+        self.map.len()
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        if let Some((dir, _)) = self.heads.iter().find(|(_, p)| p.x as usize == x && p.y as usize == y) {
+            return Cell::colored(match dir {
+                Direction::North => '^',
+                Direction::South => 'v',
+                Direction::West => '<',
+                Direction::East => '>',
+            }, Color::Yellow);
+        }
+        let ch = match self.map[y][x] {
+            NodeType::Empty => if self.visited[y][x] > 0 { '#' } else { '.' },
+            NodeType::MirrorSlash => '/',
+            NodeType::MirrorBackSlash => '\\',
+            NodeType::SplitterPipe => '|',
+            NodeType::SplitterDash => '-',
+        };
+        if self.visited[y][x] > 0 { Cell::colored(ch, Color::Red) } else { Cell::plain(ch) }
+    }
+}
+
+fn parse_map(input: &str) -> Result<Vec<Vec<NodeType>>, ParseError> {
+    parse::grid(input, |_, _, c| {
+        match c {
+            '.' => Some(NodeType::Empty),
+            '/' => Some(NodeType::MirrorSlash),
+            '\\' => Some(NodeType::MirrorBackSlash),
+            '-' => Some(NodeType::SplitterDash),
+            '|' => Some(NodeType::SplitterPipe),
+            _ => None,
+        }
+    })
+}
+
+// How many times the beam from `start` passes every tile, drawn front by front when `draw` is set
+fn energize(map: &[Vec<NodeType>], start: (Direction, Pos), draw: bool) -> Vec<Vec<i32>> {
+    let mut visited = vec![vec![0_i32; map[0].len()]; map.len()];
+    let mut loops = HashMap::<(Direction, Pos), bool>::new();
+    let mut current_positions = vec![start];
+    // the beams of the current front left to move, the next front is drawn after them
+    let mut front_left = 0;
+    let mut front = 0;
+    while !current_positions.is_empty() {
+        if draw && visualize::enabled() && front_left == 0 {
+            front += 1;
+            visualize::frame(&format!("front {}", front), &Beams { map, visited: &visited, heads: &current_positions });
+            front_left = current_positions.len();
+        }
+        front_left = front_left.saturating_sub(1);
+        let current_position = current_positions.remove(0);
+        visited[(current_position.1).y as usize][(current_position.1).x as usize] += 1;
+        if !loops.contains_key(&current_position) {
+            let moves = (current_position.1).move_on_map(&current_position.0, map);
+            moves.iter().for_each(|p| {
+                if let Some(x) = &p.1 {
+                    current_positions.push((p.0.clone(), x.clone())); 
+                }
+            });
+        }
+        loops.insert(current_position, true);
+    }
+    if draw {
+        visualize::frame("energized", &Beams { map, visited: &visited, heads: &[] });
+        visualize::finish();
+    }
+    visited
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i32, ParseError> {
+    let map = parse_map(input)?;
+    let visited = energize(&map, (Direction::East, Pos {x: 0, y: 0}), true);
+    Ok(narrow(visited.iter()
+        .flatten()
+        .filter(|v| **v > 0)
+        .count()))
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i32, ParseError> {
+    let map = parse_map(input)?;
+    let mut starting_positions = Vec::<(Direction, Pos)>::new();
+    (0..map.len()).for_each(|y| {
+        starting_positions.push((Direction::East, Pos {x: 0, y: y as i32}));
+        starting_positions.push((Direction::West, Pos {x: map[0].len() as i32 -1, y: y as i32}));
+    });
+    (0..map[0].len()).for_each(|x| {
+        starting_positions.push((Direction::South, Pos {x: x as i32, y: 0}));
+        starting_positions.push((Direction::North, Pos {x: x as i32, y: map.len() as i32 -1}));
+    });
+    
+    let energized = parallel::map(&starting_positions, |sp| {
+        narrow::<i32, usize>(energize(&map, sp.clone(), false).iter()
+            .flatten()
+            .filter(|v| **v > 0)
+            .count())
+    });
+    Ok(energized.into_iter().max().unwrap())
+}
+
+// The tiles energized by the beam of part 1, brighter the more beams pass them
+pub fn export(input: &str) -> Result<Vec<(&'static str, Image)>, ParseError> {
+    let map = parse_map(input)?;
+    let visited = energize(&map, (Direction::East, Pos {x: 0, y: 0}), false);
+    let max = visited.iter().flatten().copied().max().unwrap_or(1).max(1);
+    Ok(vec![("energized", Image::from_grid(&map, |x, y, node| {
+        match (visited[y][x], node) {
+            (0, NodeType::Empty) => image::BLACK,
+            (0, _) => image::GRAY,
+            (v, _) => image::gradient([120, 20, 20], image::YELLOW, (v - 1) as f64 / max as f64),
+        }
+    }))])
+}
+
+// Square contraption with 5% of every kind of mirror and splitter
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.below(20) {
+                    0 => '/',
+                    1 => '\\',
+                    2 => '|',
+                    3 => '-',
+                    _ => '.',
+                })
+                .collect::<String>() + "\n"
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{bench, rng::Rng, snapshot};
+
+    #[test]
+    fn simple_case() {
+        let input = ".|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 46);
+        assert_eq!(p2, 51);
+    }
+
+    #[test]
+    fn energized_snapshot() {
+        let input = ".|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....";
+        let map = parse_map(input).unwrap();
+        let visited = energize(&map, (Direction::East, Pos { x: 0, y: 0 }), false);
+        let energized = visualize::render(&Beams { map: &map, visited: &visited, heads: &[] }, false);
+        snapshot::assert_snapshot("2023/day16_energized", &energized);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_move_on_map() {
+        let mut rng = Rng::new(16);
+        let input = (0..110)
+            .map(|_| (0..110).map(|_| *rng.choose(&['.', '.', '.', '.', '.', '.', '/', '\\', '-', '|'])).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+        let map = parse_map(&input).unwrap();
+        let positions = (0..110)
+            .flat_map(|y| (0..110).map(move |x| Pos { x, y }))
+            .collect::<Vec<Pos>>();
+        bench::bench("day16 move_on_map (all cells)", || {
+            positions.iter()
+                .map(|pos| {
+                    [Direction::North, Direction::South, Direction::West, Direction::East].iter()
+                        .map(|dir| pos.move_on_map(dir, &map).len())
+                        .sum::<usize>()
+                })
+                .sum::<usize>()
+        });
+    }
+}
@@ -1,18 +1,6 @@
-use std::{fs, collections::HashMap};
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap}};
 
-pub fn solve() {
-    let input = fs::read_to_string("./inputs/input_17.txt")
-        .expect("File not found")
-        .lines()
-        .map(|l: &str| l.to_string())
-        .collect::<Vec<String>>();
-
-    let p1 = solve_part_1(input.clone());
-    let p2 = solve_part_2(input);
-
-    println!("Part 1 solution = {}", p1); 
-    println!("Part 2 solution = {}", p2); 
-}
+use crate::utils::{image::{self, Image}, rng::Rng, parse::{self, ParseError}};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Pos {
@@ -38,20 +26,18 @@ struct Path {
 }
 
 
-fn parse_map(input: &[String]) -> Vec<Vec<usize>> {
-    input.iter()
-        .map(|line| {
-            line.chars()
-                .map(|c| {
-                    c.to_digit(10).unwrap() as usize
-                })
-                .collect()
-        })
-        .collect()
+fn parse_map(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    let map = parse::grid(input, |_, _, c| {
+        c.to_digit(10).map(|d| d as usize)
+    })?;
+    if map.len() < 2 || map[0].len() < 2 {
+        return Err(ParseError { line: 1, column: 1, message: "expected at least a 2x2 map".to_string() });
+    }
+    Ok(map)
 }
 
-fn solve_part_1(input: Vec<String>) -> usize {
-    let map = parse_map(&input);
+pub(crate) fn solve_part_1(input: &str) -> Result<usize, ParseError> {
+    let map = parse_map(input)?;
     let mut goal_heat_loss = (map.len()+map[0].len())*9;
     let mut current_positions = vec![Path{pos: Pos {x: 0, y: 0}, is_starting: true, straight_count: 0, heat_loss: 0, from: Direction::North }]; 
     let mut visited = HashMap::<(Pos, Direction, u8), usize>::new();
@@ -63,12 +49,7 @@ fn solve_part_1(input: Vec<String>) -> usize {
                     .unwrap();
         let current_position = current_positions.remove(current_positions.iter().position(|x| x == max_ele).unwrap());
         if current_position.pos.x == map[0].len() as i32 - 1 && current_position.pos.y == map.len() as i32 - 1 {
-            if goal_heat_loss >= current_position.heat_loss {
-                println!("new best: {} -> {}", goal_heat_loss, current_position.heat_loss);
-                goal_heat_loss = current_position.heat_loss;
-            } else {
-                println!("end : {} ({})", current_position.heat_loss, current_positions.len());
-            }
+            goal_heat_loss = goal_heat_loss.min(current_position.heat_loss);
         } else if current_position.heat_loss + (map.len() + map[0].len() - current_position.pos.x as usize- current_position.pos.y as usize) < goal_heat_loss && 
                 !(current_position.pos.x == 0 && current_position.pos.y == 0 && !current_position.is_starting){
             match visited.get(&(current_position.pos.clone(), current_position.from.clone(), current_position.straight_count)) {
@@ -86,7 +67,7 @@ fn solve_part_1(input: Vec<String>) -> usize {
         }
     }
 
-    goal_heat_loss
+    Ok(goal_heat_loss)
 }
 
 fn add_moves(cp: &Path, current_positions: &mut Vec<Path>, map: &[Vec<usize>]) {
@@ -378,8 +359,8 @@ fn add_moves_ultra(cp: &Path, current_positions: &mut Vec<Path>, map: &[Vec<usiz
     }
 }
 
-fn solve_part_2(input: Vec<String>) -> usize {
-    let map = parse_map(&input);
+pub(crate) fn solve_part_2(input: &str) -> Result<usize, ParseError> {
+    let map = parse_map(input)?;
     let mut goal_heat_loss = (map.len()+map[0].len())*9;
     let mut current_positions = vec![Path{pos: Pos {x: 0, y: 0}, is_starting: true, straight_count: 0, heat_loss: 0, from: Direction::North }]; 
     let mut visited = HashMap::<(Pos, Direction, u8), usize>::new();
@@ -391,12 +372,7 @@ fn solve_part_2(input: Vec<String>) -> usize {
                     .unwrap();
         let current_position = current_positions.remove(current_positions.iter().position(|x| x == max_ele).unwrap());
         if current_position.pos.x == map[0].len() as i32 - 1 && current_position.pos.y == map.len() as i32 - 1 {
-            if goal_heat_loss >= current_position.heat_loss {
-                println!("new best: {} -> {}", goal_heat_loss, current_position.heat_loss);
-                goal_heat_loss = current_position.heat_loss;
-            } else {
-                println!("end : {} ({})", current_position.heat_loss, current_positions.len());
-            }
+            goal_heat_loss = goal_heat_loss.min(current_position.heat_loss);
         } else if current_position.heat_loss + (map.len() + map[0].len() - current_position.pos.x as usize- current_position.pos.y as usize) < goal_heat_loss && 
                 !(current_position.pos.x == 0 && current_position.pos.y == 0 && !current_position.is_starting){
             match visited.get(&(current_position.pos.clone(), current_position.from.clone(), current_position.straight_count)) {
@@ -414,13 +390,89 @@ fn solve_part_2(input: Vec<String>) -> usize {
         }
     }
 
-    goal_heat_loss
+    Ok(goal_heat_loss)
+}
+
+// The blocks of a cheapest path of a crucible going min..=max blocks straight between turns, the
+// parts only search for its heat loss. Dijkstra over (block, moved horizontally) where one move
+// is a whole straight line.
+fn optimal_path(map: &[Vec<usize>], min_straight: usize, max_straight: usize) -> (usize, Vec<(usize, usize)>) {
+    let (width, height) = (map[0].len() as i64, map.len() as i64);
+    let mut heat_loss = HashMap::<(i64, i64, bool), usize>::new();
+    let mut previous = HashMap::<(i64, i64, bool), (i64, i64, bool)>::new();
+    let mut queue = BinaryHeap::new();
+    for horizontal in [true, false] {
+        heat_loss.insert((0, 0, horizontal), 0);
+        queue.push(Reverse((0, (0, 0, horizontal))));
+    }
+    while let Some(Reverse((loss, state))) = queue.pop() {
+        let (x, y, horizontal) = state;
+        if loss > heat_loss[&state] {
+            continue;
+        }
+        if x == width - 1 && y == height - 1 {
+            let mut path = vec![(x as usize, y as usize)];
+            let mut current = state;
+            while let Some(prev) = previous.get(&current) {
+                let (dx, dy) = ((prev.0 - current.0).signum(), (prev.1 - current.1).signum());
+                let (mut cx, mut cy) = (current.0, current.1);
+                while (cx, cy) != (prev.0, prev.1) {
+                    (cx, cy) = (cx + dx, cy + dy);
+                    path.push((cx as usize, cy as usize));
+                }
+                current = *prev;
+            }
+            path.reverse();
+            return (loss, path);
+        }
+        // turn and go straight
+        let directions = if horizontal { [(0, 1), (0, -1)] } else { [(1, 0), (-1, 0)] };
+        for (dx, dy) in directions {
+            let mut next_loss = loss;
+            for steps in 1..=max_straight as i64 {
+                let (nx, ny) = (x + dx * steps, y + dy * steps);
+                if nx < 0 || ny < 0 || nx >= width || ny >= height {
+                    break;
+                }
+                next_loss += map[ny as usize][nx as usize];
+                let next = (nx, ny, !horizontal);
+                if steps as usize >= min_straight && heat_loss.get(&next).is_none_or(|l| next_loss < *l) {
+                    heat_loss.insert(next, next_loss);
+                    previous.insert(next, state);
+                    queue.push(Reverse((next_loss, next)));
+                }
+            }
+        }
+    }
+    (0, vec![])
+}
+
+// Heat maps with the best paths of both crucibles
+pub fn export(input: &str) -> Result<Vec<(&'static str, Image)>, ParseError> {
+    let map = parse_map(input)?;
+    let heat_map = Image::from_grid(&map, |_, _, loss| image::gradient([20, 20, 40], [255, 140, 0], (*loss as f64 - 1.0) / 8.0));
+    Ok([("path_part_1", 1, 3), ("path_part_2", 4, 10)].iter()
+        .map(|(name, min, max)| {
+            let mut image = heat_map.clone();
+            optimal_path(&map, *min, *max).1.iter().for_each(|(x, y)| image.set(*x, *y, image::WHITE));
+            (*name, image)
+        })
+        .collect())
+}
+
+// Square map of at least 5x5 blocks (the ultra crucible needs room to get to the end)
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    (0..size)
+        .map(|_| (0..size).map(|_| (b'1' + rng.below(9) as u8) as char).collect::<String>() + "\n")
+        .collect()
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::{bench, rng::Rng};
 
     #[test]
     fn simple_case() {
@@ -436,12 +488,61 @@ mod tests {
 4564679986453
 1224686865563
 2546548887735
-4322674655533".split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        let p1 = solve_part_1(input.clone());
-        let p2 = solve_part_2(input);
+4322674655533";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
         assert_eq!(p1, 102);
         assert_eq!(p2, 94);
     }
+
+    #[test]
+    fn optimal_paths() {
+        let input = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+        let map = parse_map(input).unwrap();
+        let (loss, path) = optimal_path(&map, 1, 3);
+        assert_eq!(loss, 102);
+        assert_eq!(path.iter().skip(1).map(|(x, y)| map[*y][*x]).sum::<usize>(), 102);
+        assert_eq!((path[0], path[path.len() - 1]), ((0, 0), (12, 12)));
+        assert_eq!(optimal_path(&map, 4, 10).0, 94);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_add_moves() {
+        let mut rng = Rng::new(17);
+        let input = (0..141)
+            .map(|_| (0..141).map(|_| char::from_digit(1 + rng.below(9) as u32, 10).unwrap()).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+        let map = parse_map(&input).unwrap();
+        let paths = (0..141)
+            .flat_map(|y| (0..141).map(move |x| Pos { x, y }))
+            .flat_map(|pos| {
+                [Direction::North, Direction::South, Direction::West, Direction::East].into_iter()
+                    .map(move |from| Path { straight_count: 1 + (pos.x + pos.y) as u8 % 3, pos: pos.clone(), from, is_starting: false, heat_loss: 0 })
+            })
+            .collect::<Vec<Path>>();
+        let mut current_positions = Vec::with_capacity(8);
+        bench::bench("day17 add_moves (all cells)", || {
+            paths.iter()
+                .map(|path| {
+                    current_positions.clear();
+                    add_moves(path, &mut current_positions, &map);
+                    current_positions.len()
+                })
+                .sum::<usize>()
+        });
+    }
 }
@@ -0,0 +1,259 @@
+use crate::utils::{image::{self, Image}, polygon, rng::{self, Rng}, parse::{self, Cursor, ParseError}};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct Instruction {
+    dir: Direction,
+    num_steps: u32,
+    color: u32, 
+}
+
+// `R 6 (#70c710)`, returns the direction, the steps and the 6 hex digits of the color
+fn split_instruction(line: Cursor) -> Result<(Cursor, Cursor, Cursor), ParseError> {
+    let mut words = line.words();
+    match (words.next(), words.next(), words.next(), words.next()) {
+        (Some(dir), Some(steps), Some(color), None) => {
+            let (_, hex) = color.braced('(', ')')?;
+            let hex = hex.expect("#")?;
+            if hex.len() != 6 || !hex.as_str().chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(hex.error("expected 6 hex digits"));
+            }
+            Ok((dir, steps, hex))
+        },
+        _ => Err(line.error("expected `<direction> <steps> (#<color>)`")),
+    }
+}
+
+fn parse_instruction(line: Cursor) -> Result<Instruction, ParseError> {
+    let (dir, steps, hex) = split_instruction(line)?;
+    Ok(Instruction {dir: match dir.as_str() {
+            "D" => Direction::Down,
+            "U" => Direction::Up,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(dir.error("invalid direction")),
+        },
+        num_steps: steps.parse::<u32>()?,
+        color: u32::from_str_radix(hex.as_str(), 16).unwrap(),
+    })
+}
+
+// part 2 hides the real instruction in the color: 5 hex digits of steps and the direction digit
+fn parse_instruction_from_color(line: Cursor) -> Result<Instruction, ParseError> {
+    let (_, _, hex) = split_instruction(line)?;
+    let (steps, dir) = hex.split_at(5)?;
+    let dir = match dir.as_str() {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => return Err(dir.error("invalid direction")),
+        };
+    let num_steps = u32::from_str_radix(steps.as_str(), 16).unwrap();
+    Ok(Instruction { dir, num_steps, color: u32::from_str_radix(hex.as_str(), 16).unwrap() })
+}
+
+// The trench is a rectilinear lattice polygon, the lagoon is every lattice point inside or on it
+fn lagoon_volume(instructions: &[Instruction]) -> i64 {
+    let vertices = polygon::from_moves(instructions.iter()
+        .map(|instr| {
+            let n = instr.num_steps as i64;
+            match instr.dir {
+                Direction::Down => (0, n),
+                Direction::Up => (0, -n),
+                Direction::Left => (-n, 0),
+                Direction::Right => (n, 0),
+            }
+        }));
+    polygon::total_points(&vertices)
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i64, ParseError> {
+    let instructions = parse::lines(input)
+        .map(parse_instruction)
+        .collect::<Result<Vec<Instruction>, ParseError>>()?;
+    Ok(lagoon_volume(&instructions))
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i64, ParseError> {
+    let instructions = parse::lines(input)
+        .map(parse_instruction_from_color)
+        .collect::<Result<Vec<Instruction>, ParseError>>()?;
+    Ok(lagoon_volume(&instructions))
+}
+
+// The lagoon of part 1 with the trench painted in the colors of the instructions
+pub fn export(input: &str) -> Result<Vec<(&'static str, Image)>, ParseError> {
+    let instructions = parse::lines(input)
+        .map(parse_instruction)
+        .collect::<Result<Vec<Instruction>, ParseError>>()?;
+    let mut trench = vec![];
+    let (mut x, mut y) = (0_i64, 0_i64);
+    for instr in instructions.iter() {
+        let (dx, dy) = match instr.dir {
+            Direction::Down => (0, 1),
+            Direction::Up => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        };
+        for _ in 0..instr.num_steps {
+            (x, y) = (x + dx, y + dy);
+            trench.push((x, y, instr.color));
+        }
+    }
+    let min_x = trench.iter().map(|t| t.0).min().unwrap_or(0) - 1;
+    let min_y = trench.iter().map(|t| t.1).min().unwrap_or(0) - 1;
+    let width = (trench.iter().map(|t| t.0).max().unwrap_or(0) - min_x + 2) as usize;
+    let height = (trench.iter().map(|t| t.1).max().unwrap_or(0) - min_y + 2) as usize;
+
+    // everything the outside flood does not reach is lagoon
+    let mut image = Image::new(width, height, [70, 70, 90]);
+    let mut dug = vec![vec![false; width]; height];
+    for (x, y, color) in trench {
+        let (x, y) = ((x - min_x) as usize, (y - min_y) as usize);
+        dug[y][x] = true;
+        image.set(x, y, [(color >> 16) as u8, (color >> 8) as u8, color as u8]);
+    }
+    let mut stack = vec![(0, 0)];
+    while let Some((x, y)) = stack.pop() {
+        if dug[y][x] {
+            continue;
+        }
+        dug[y][x] = true;
+        image.set(x, y, image::BLACK);
+        if x > 0 { stack.push((x - 1, y)); }
+        if y > 0 { stack.push((x, y - 1)); }
+        if x + 1 < width { stack.push((x + 1, y)); }
+        if y + 1 < height { stack.push((x, y + 1)); }
+    }
+    Ok(vec![("lagoon", image)])
+}
+
+// The colors hide a second, much larger lagoon with the same number of moves
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let columns = 1 + rng.below(size.max(1));
+    let small = rng::random_rectilinear(rng, columns, size as i64);
+    let large = rng::random_rectilinear(rng, columns, (size as i64 * 10000).min(100000));
+    // (direction, its digit in the color, steps)
+    let instruction = |(dx, dy): &(i64, i64)| match (dx.signum(), dy.signum()) {
+        (1, _) => ('R', 0, *dx),
+        (-1, _) => ('L', 2, -dx),
+        (_, 1) => ('D', 1, *dy),
+        _ => ('U', 3, -dy),
+    };
+    small.iter()
+        .zip(large.iter())
+        .map(|(small_move, large_move)| {
+            let (dir, _, steps) = instruction(small_move);
+            let (_, color_dir, color_steps) = instruction(large_move);
+            format!("{} {} (#{:05x}{})\n", dir, steps, color_steps, color_dir)
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::property;
+
+    #[test]
+    fn simple_case() {
+        let input = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 62);
+        // assert_eq!(p2, 62);
+        assert_eq!(p2, 952408144115);
+    }
+
+    #[test]
+    fn flood_fill_reference() {
+        let input = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)".split('\n')
+            .map(|line| parse_instruction(Cursor::new(1, line)).unwrap())
+            .collect::<Vec<Instruction>>();
+        assert_eq!(lagoon_volume_flood_fill(&input), 62);
+        assert_eq!(lagoon_volume_flood_fill(&input), lagoon_volume(&input));
+    }
+
+    #[test]
+    fn differential() {
+        property::differential(200,
+            |rng| generate(rng, 8).lines()
+                .map(|line| parse_instruction(Cursor::new(1, line)).unwrap())
+                .collect::<Vec<Instruction>>(),
+            |instructions| lagoon_volume_flood_fill(instructions),
+            |instructions| lagoon_volume(instructions));
+    }
+
+    // Reference solution: dig the trench into a grid and flood fill the outside
+    fn lagoon_volume_flood_fill(instructions: &[Instruction]) -> i64 {
+        let mut trench = vec![(0_i64, 0_i64)];
+        instructions.iter()
+            .for_each(|instr| {
+                let (dx, dy) = match instr.dir {
+                    Direction::Down => (0, 1),
+                    Direction::Up => (0, -1),
+                    Direction::Left => (-1, 0),
+                    Direction::Right => (1, 0),
+                };
+                (0..instr.num_steps).for_each(|_| {
+                    let last = trench[trench.len()-1];
+                    trench.push((last.0 + dx, last.1 + dy));
+                });
+            });
+        let min_x = trench.iter().map(|p| p.0).min().unwrap() - 1;
+        let min_y = trench.iter().map(|p| p.1).min().unwrap() - 1;
+        let width = (trench.iter().map(|p| p.0).max().unwrap() - min_x + 2) as usize;
+        let height = (trench.iter().map(|p| p.1).max().unwrap() - min_y + 2) as usize;
+
+        let mut dug = vec![vec![false; width]; height];
+        trench.iter().for_each(|(x, y)| dug[(y - min_y) as usize][(x - min_x) as usize] = true);
+        let mut outside = vec![vec![false; width]; height];
+        let mut stack = vec![(0_usize, 0_usize)];
+        while let Some((x, y)) = stack.pop() {
+            if outside[y][x] || dug[y][x] {
+                continue;
+            }
+            outside[y][x] = true;
+            if x > 0 { stack.push((x - 1, y)); }
+            if y > 0 { stack.push((x, y - 1)); }
+            if x + 1 < width { stack.push((x + 1, y)); }
+            if y + 1 < height { stack.push((x, y + 1)); }
+        }
+        (width * height - outside.iter().flatten().filter(|o| **o).count()) as i64
+    }
+}
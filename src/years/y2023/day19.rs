@@ -0,0 +1,372 @@
+use std::{collections::{HashMap, HashSet}, ops::Range};
+
+use crate::utils::{dot::Graph, parallel, rng::Rng, parse::{self, Cursor, ParseError}};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum PartType {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug)]
+enum Comp {
+    Greater,
+    Less,
+}
+
+// (rating, comparator, value, next workflow)
+type Rule<'a> = (PartType, Comp, i32, &'a str);
+// rules and the workflow used when none of the rules match
+type Workflow<'a> = (Vec<Rule<'a>>, &'a str);
+type Part = HashMap<PartType, i32>;
+
+fn parse_part_type(c: Cursor) -> Result<PartType, ParseError> {
+    match c.as_str() {
+        "x" => Ok(PartType::X),
+        "m" => Ok(PartType::M),
+        "a" => Ok(PartType::A),
+        "s" => Ok(PartType::S),
+         _  => Err(c.error("invalid part type")),
+    }
+}
+
+fn parse_target<'a>(target: Cursor<'a>, names: &HashSet<&str>) -> Result<&'a str, ParseError> {
+    match target.as_str() {
+        "A" | "R" => Ok(target.as_str()),
+        t if names.contains(t) => Ok(t),
+        t => Err(target.error(format!("unknown workflow `{}`", t))),
+    }
+}
+
+fn parse_workflow<'a>(line: Cursor<'a>, names: &HashSet<&str>) -> Result<(&'a str, Workflow<'a>), ParseError> {
+    let (name, body) = line.braced('{', '}')?;
+    let rules_str: Vec<Cursor> = body.split(",").collect();
+    let (fallback, rules_str) = rules_str.split_last().expect("Split returns at least one piece");
+    let rules = rules_str.iter()
+        .map(|rule| {
+            let (condition, target) = rule.split_once(":")?;
+            let (part_type, condition) = condition.split_at(1)?;
+            let (comp, value) = condition.split_at(1)?;
+            let comp = match comp.as_str() {
+                ">" => Comp::Greater,
+                "<" => Comp::Less,
+                _ => return Err(comp.error("invalid comparator")),
+            };
+            Ok((parse_part_type(part_type)?, comp, value.parse::<i32>()?, parse_target(target, names)?))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((name.as_str(), (rules, parse_target(*fallback, names)?)))
+}
+
+fn parse_workflows<'a>(lines: &[Cursor<'a>]) -> Result<HashMap<&'a str, Workflow<'a>>, ParseError> {
+    let names = lines.iter()
+        .filter_map(|line| line.split_once("{").ok())
+        .map(|(name, _)| name.as_str())
+        .collect::<HashSet<&str>>();
+    let workflows = lines.iter()
+        .map(|line| parse_workflow(*line, &names))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    if !workflows.contains_key("in") {
+        return Err(ParseError { line: 1, column: 1, message: "missing the `in` workflow".to_string() });
+    }
+    Ok(workflows)
+}
+
+fn parse_part(line: Cursor) -> Result<Part, ParseError> {
+    let (name, body) = line.braced('{', '}')?;
+    name.end()?;
+    let part = body.split(",")
+        .map(|p| {
+            let (part_type, value) = p.key_value("=")?;
+            Ok((parse_part_type(part_type)?, value.parse::<i32>()?))
+        })
+        .collect::<Result<HashMap::<PartType, i32>, ParseError>>()?;
+    if part.len() != 4 {
+        return Err(body.error("expected x, m, a and s ratings"));
+    }
+    Ok(part)
+}
+
+fn parse(input: &str) -> Result<(HashMap<&str, Workflow<'_>>, Vec<Part>), ParseError> {
+    match parse::sections(input).as_slice() {
+        [workflows] => Ok((parse_workflows(workflows)?, vec![])),
+        [workflows, parts] => Ok((parse_workflows(workflows)?, parts.iter().map(|p| parse_part(*p)).collect::<Result<_, _>>()?)),
+        _ => Err(ParseError { line: 1, column: 1, message: "expected workflows and parts separated by a blank line".to_string() }),
+    }
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i32, ParseError> {
+    let (filters, parts) = parse(input)?;
+    let valid = parallel::map(&parts, |p| is_part_valid(p, filters.get("in").unwrap(), &filters));
+
+    Ok(parts.iter()
+        .zip(valid)
+        .fold(0, |acc, (p, valid)| {
+            if valid {
+                acc + p.values().sum::<i32>()
+            } else {
+                acc
+            }
+        }))
+}
+
+fn is_part_valid(p: &HashMap<PartType, i32>, current_filter: &Workflow, filters: &HashMap<&str, Workflow>) -> bool {
+    let mut next_filter: &str = current_filter.1;
+    for filter in current_filter.0.iter() {
+        match filter.1 {
+            Comp::Less => {
+                if p.get(&filter.0).unwrap() < &filter.2 {
+                    next_filter = filter.3; 
+                    break;
+                }
+            },
+            Comp::Greater => {
+                if p.get(&filter.0).unwrap() > &filter.2 {
+                    next_filter = filter.3; 
+                    break;
+                }
+            },
+        }
+    }
+
+    if next_filter == "A" {
+        true
+    } else if next_filter == "R" {
+        false
+    } else {
+        is_part_valid(p, filters.get(next_filter).unwrap(), filters)
+    }
+}
+
+fn is_part_valid_ranges(p: &HashMap<PartType, Range<i32>>, current_filter: &Workflow, filters: &HashMap<&str, Workflow>) -> i64 {
+    let mut current_part_splits = vec![p.clone()]; 
+    let mut part_splits_next_filter: Vec<(HashMap<PartType, Range<i32>>, &str)> = vec![];
+    for filter in current_filter.0.iter() {
+        let mut next_part_splits = vec![];
+        for split in current_part_splits {
+            let r = split.get(&filter.0).unwrap();
+            match filter.1 {
+                Comp::Less => {
+                    if r.contains(&filter.2) {
+                        let mut split_match= split.clone();
+                        split_match.insert(filter.0.clone(), r.start..filter.2);
+                        let mut split_not_match = split.clone();
+                        split_not_match.insert(filter.0.clone(), filter.2..r.end);
+                        part_splits_next_filter.push((split_match, filter.3));
+                        next_part_splits.push(split_not_match);
+                    } else if r.end <= filter.2 {
+                        part_splits_next_filter.push((split, filter.3));
+                    } else {
+                        next_part_splits.push(split);
+                    }
+                },
+                Comp::Greater => {
+                    if r.contains(&filter.2) {
+                        let mut split_match= split.clone();
+                        split_match.insert(filter.0.clone(), filter.2+1..r.end );
+                        let mut split_not_match = split.clone();
+                        split_not_match.insert(filter.0.clone(), r.start..filter.2+1);
+                        part_splits_next_filter.push((split_match, filter.3));
+                        next_part_splits.push(split_not_match);
+                    } else if r.start > filter.2 {
+                        part_splits_next_filter.push((split, filter.3));
+                    } else {
+                        next_part_splits.push(split);
+                    }
+                },
+            }
+        }
+        current_part_splits = next_part_splits;
+    }
+
+    for split in current_part_splits {
+        part_splits_next_filter.push((split, current_filter.1));
+    }
+
+    part_splits_next_filter.into_iter()
+        .fold(0, |total, (p, next_filter)| {
+            if next_filter == "A" {
+                total + p.into_iter()
+                    .fold(1, |acc, (_, r)| {
+                        acc * r.len() as i64 
+                    })
+            } else if next_filter == "R" {
+                total
+            } else {
+                total + is_part_valid_ranges(&p, filters.get(next_filter).unwrap(), filters)
+            }
+        })
+}
+
+// Number of accepted parts with all ratings in 1..=max_rating
+fn count_accepted(filters: &HashMap<&str, Workflow>, max_rating: i32) -> i64 {
+    let mut part = HashMap::<PartType, Range<i32>>::new();
+    part.insert(PartType::X, 1..max_rating+1);
+    part.insert(PartType::M, 1..max_rating+1);
+    part.insert(PartType::A, 1..max_rating+1);
+    part.insert(PartType::S, 1..max_rating+1);
+
+    is_part_valid_ranges(&part, filters.get("in").unwrap(), filters)
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i64, ParseError> {
+    let (filters, _) = parse(input)?;
+    Ok(count_accepted(&filters, 4000))
+}
+
+// Workflows form a tree-like DAG from `in` (rules only point to later workflows, so nothing loops),
+// followed by parts with ratings in 1..=max_rating
+fn generate_with_ratings(rng: &mut Rng, size: usize, max_rating: i32) -> String {
+    let mut names = vec!["in".to_string()];
+    while names.len() < size.max(1) {
+        let name = (0..2 + rng.below(2))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect::<String>();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut ret = String::new();
+    for i in 0..names.len() {
+        let target = |rng: &mut Rng| {
+            let next = i + 1 + rng.below(names.len() - i + 1);
+            match names.get(next) {
+                Some(name) => name.clone(),
+                None if rng.chance(0.5) => "A".to_string(),
+                None => "R".to_string(),
+            }
+        };
+        let rules = (0..1 + rng.below(3))
+            .map(|_| format!("{}{}{}:{}", rng.choose(&['x', 'm', 'a', 's']), rng.choose(&['<', '>']),
+                             1 + rng.below(max_rating as usize), target(rng)))
+            .collect::<Vec<String>>();
+        ret += &format!("{}{{{},{}}}\n", names[i], rules.join(","), target(rng));
+    }
+    ret.push('\n');
+    for _ in 0..size.max(1) {
+        let mut rating = || 1 + rng.below(max_rating as usize);
+        ret += &format!("{{x={},m={},a={},s={}}}\n", rating(), rating(), rating(), rating());
+    }
+    ret
+}
+
+// The workflow tree, every rule is an edge labelled by its condition. `in` is a house, A and R are
+// the double circle and the octagon.
+pub fn dot(input: &str) -> Result<Graph, ParseError> {
+    let (workflows, _) = parse(input)?;
+    let mut names = workflows.keys().copied().collect::<Vec<&str>>();
+    names.sort();
+
+    let mut graph = Graph::new();
+    graph.node("A", "doublecircle");
+    graph.node("R", "octagon");
+    for name in names.iter() {
+        let (rules, fallback) = &workflows[name];
+        graph.node(name, if *name == "in" { "invhouse" } else { "box" });
+        for (part_type, comp, value, next) in rules.iter() {
+            let rating = match part_type {
+                PartType::X => 'x',
+                PartType::M => 'm',
+                PartType::A => 'a',
+                PartType::S => 's',
+            };
+            let comp = match comp {
+                Comp::Greater => '>',
+                Comp::Less => '<',
+            };
+            graph.edge(name, next, Some(format!("{}{}{}", rating, comp, value)));
+        }
+        graph.edge(name, fallback, Some("else".to_string()));
+    }
+    Ok(graph)
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    generate_with_ratings(rng, size, 4000)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fuzz;
+    use crate::utils::property;
+    use crate::utils::snapshot;
+
+    #[test]
+    fn simple_case() {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 19114);
+        assert_eq!(p2, 167409079868000);
+    }
+
+    #[test]
+    fn dot_snapshot() {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+rfg{s<537:gd,x>2440:R,A}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,pv}
+gd{a>3333:R,R}
+
+{x=787,m=2655,a=1222,s=2876}";
+        snapshot::assert_snapshot("2023/day19_dot", &dot(input).unwrap().to_string());
+    }
+
+    #[test]
+    fn differential() {
+        // reference: run every single part of a small rating range through the workflows
+        let max_rating = 5;
+        let brute_force = |input: &String| {
+            let (filters, _) = parse(input).unwrap();
+            let ratings = 1..max_rating + 1;
+            let mut accepted = 0;
+            for x in ratings.clone() {
+                for m in ratings.clone() {
+                    for a in ratings.clone() {
+                        for s in ratings.clone() {
+                            let part = HashMap::from([(PartType::X, x), (PartType::M, m), (PartType::A, a), (PartType::S, s)]);
+                            if is_part_valid(&part, filters.get("in").unwrap(), &filters) {
+                                accepted += 1;
+                            }
+                        }
+                    }
+                }
+            }
+            accepted
+        };
+        property::differential(200,
+            |rng| generate_with_ratings(rng, 6, max_rating),
+            brute_force,
+            |input| count_accepted(&parse(input).unwrap().0, max_rating));
+    }
+
+    #[test]
+    fn fuzz_parser() {
+        fuzz::fuzz(&["px{a<2006:qkq,m>2090:A,rfg}\nin{s<1351:px,qqz}\nqqz{s>2770:A,m<1801:px,R}\nqkq{x<1416:A,R}\nrfg{A}\n\n{x=787,m=2655,a=1222,s=2876}\n{x=1679,m=44,a=2067,s=496}",
+                     "in{x>10:A,R}\n\n{x=1,m=2,a=3,s=4}"],
+                   |input| { let _ = parse(input); });
+    }
+}
@@ -1,18 +1,6 @@
-use std::{fs, collections::{HashMap, BTreeMap}};
+use std::collections::{HashMap, BTreeMap};
 
-pub fn solve() {
-    let input = fs::read_to_string("./inputs/input_20.txt")
-        .expect("File not found")
-        .lines()
-        .map(|l: &str| l.to_string())
-        .collect::<Vec<String>>();
-
-    let p1 = solve_part_1(input.clone());
-    let p2 = solve_part_2(input);
-
-    println!("Part 1 solution = {}", p1); 
-    println!("Part 2 solution = {}", p2); 
-}
+use crate::utils::{checked, dot::Graph, rng::Rng, parse::{self, Cursor, ParseError}};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum ModuleType {
@@ -40,36 +28,51 @@ struct Module<'a> {
     memory: Option<HashMap<&'a str, Pulse>>,
 }
 
-fn parse_modules<'a>(input: &'a [String]) -> BTreeMap<&'a str, Module> {
+fn parse_module(line: Cursor<'_>) -> Result<(&str, ModuleType, Vec<&str>), ParseError> {
+    let (module, connections) = line.split_once(" -> ")?;
+    let (module_type, module_name) = if let Some(name) = module.strip_prefix("%") {
+        (ModuleType::Flipflop(false), name)
+    } else if let Some(name) = module.strip_prefix("&") {
+        (ModuleType::Conjunction, name)
+    } else {
+        (ModuleType::Broadcast, module)
+    };
+    if module_name.is_empty() {
+        return Err(module_name.error("missing module name"));
+    }
+    let connections = connections.split(",")
+        .map(|c| {
+            let c = c.trim();
+            if c.is_empty() { Err(c.error("missing connection name")) } else { Ok(c.as_str()) }
+        })
+        .collect::<Result<Vec<&str>, ParseError>>()?;
+    Ok((module_name.as_str(), module_type, connections))
+}
+
+fn parse_modules(input: &str) -> Result<BTreeMap<&str, Module<'_>>, ParseError> {
     let mut modules = BTreeMap::<&str, Module>::new();
     let mut modules_conj = BTreeMap::<&str, Module>::new();
 
-    input.iter()
-        .for_each(|line| {
-            let mut iter = line.split(" -> ");
-            let part1 = iter.next().unwrap();
-            let module_type = match part1.chars().nth(0).unwrap() {
-                '%' => ModuleType::Flipflop(false),
-                '&' => ModuleType::Conjunction,
-                 _  => ModuleType::Broadcast,
-            };
-            let module_name = if module_type == ModuleType::Broadcast {part1} else {&part1[1..]};
-            let connections: Vec<&str> = iter.next().unwrap().split(",").map(|s| s.trim()).collect();
-
-            if module_type == ModuleType::Conjunction {
-                modules_conj.insert(module_name, 
-                               Module { r#type: module_type.clone(), 
-                                   conn: connections, 
-                                   memory: Some(HashMap::<&str, Pulse>::new()),
-                               });
-            } else {
-                modules.insert(module_name, 
-                               Module { r#type: module_type,
-                                   conn: connections, 
-                                   memory: None,
-                               });
-            }
-        });
+    for line in parse::lines(input) {
+        let (module_name, module_type, connections) = parse_module(line)?;
+        if modules.contains_key(module_name) || modules_conj.contains_key(module_name) {
+            return Err(line.error(format!("duplicate module `{}`", module_name)));
+        }
+
+        if module_type == ModuleType::Conjunction {
+            modules_conj.insert(module_name, 
+                           Module { r#type: module_type.clone(), 
+                               conn: connections, 
+                               memory: Some(HashMap::<&str, Pulse>::new()),
+                           });
+        } else {
+            modules.insert(module_name, 
+                           Module { r#type: module_type,
+                               conn: connections, 
+                               memory: None,
+                           });
+        }
+    }
 
     //fill conjuction incomming connections
     modules_conj.iter_mut()
@@ -84,12 +87,12 @@ fn parse_modules<'a>(input: &'a [String]) -> BTreeMap<&'a str, Module> {
         });
 
     modules.extend(modules_conj);
-    modules
+    Ok(modules)
 }
 
 
-fn solve_part_1(input: Vec<String>) -> u32 {
-    let mut modules = parse_modules(&input);
+pub(crate) fn solve_part_1(input: &str) -> Result<u32, ParseError> {
+    let mut modules = parse_modules(input)?;
     let mut num_pulses_per_buttonpress: Vec<(u32, u32)> = vec![];
     let initial_state = get_state(&modules);
     
@@ -167,15 +170,12 @@ fn solve_part_1(input: Vec<String>) -> u32 {
         counts.1 += num_pulses_per_buttonpress[i].1;
     });
 
-    counts.0*counts.1
+    Ok(counts.0*counts.1)
 }
 
 fn get_state(modules: &BTreeMap<&str, Module<'_>>) -> String {
     modules.iter()
-        .filter(|(_, module)| match module.r#type { 
-                ModuleType::Conjunction | ModuleType::Flipflop(_) => true,
-                _ => false, 
-        })
+        .filter(|(_, module)| matches!(module.r#type, ModuleType::Conjunction | ModuleType::Flipflop(_)))
         .map(|(_, m)| {
             match m.r#type {
                 ModuleType::Conjunction => {
@@ -198,13 +198,13 @@ fn get_state(modules: &BTreeMap<&str, Module<'_>>) -> String {
         .collect::<String>()
 }
 
-fn solve_part_2(input: Vec<String>) -> i32 {
-    let mut modules = parse_modules(&input);
+pub(crate) fn solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let mut modules = parse_modules(input)?;
     let initial_state = get_state(&modules);
     
-    let mut button_press_counter = 0; 
+    let mut button_press_counter: u64 = 0;
     loop {
-        button_press_counter += 1;
+        button_press_counter = checked::check(button_press_counter.checked_add(1), "the button presses");
 
         let mut num_low_rx_pulses = 0;
         let mut pulse_queue: Vec<Message> = vec![Message{from: "button", to: "broadcaster", pulse: Pulse::Low}];
@@ -263,13 +263,84 @@ fn solve_part_2(input: Vec<String>) -> i32 {
         }
     }
 
-    button_press_counter
+    Ok(button_press_counter)
+}
+
+// The module circuit: flip-flops are boxes, conjunctions triangles and the broadcaster a diamond fed
+// by the button. Modules without outputs (rx) are double circles.
+pub fn dot(input: &str) -> Result<Graph, ParseError> {
+    let modules = parse_modules(input)?;
+
+    let mut graph = Graph::new();
+    graph.node("button", "invhouse");
+    graph.edge("button", "broadcaster", None);
+    for (name, module) in modules.iter() {
+        graph.node(name, match module.r#type {
+            ModuleType::Flipflop(_) => "box",
+            ModuleType::Conjunction => "invtriangle",
+            ModuleType::Broadcast => "diamond",
+        });
+        module.conn.iter().for_each(|conn| graph.edge(name, conn, None));
+    }
+    let mut sinks = modules.values()
+        .flat_map(|m| m.conn.iter())
+        .filter(|conn| !modules.contains_key(*conn))
+        .collect::<Vec<&&str>>();
+    sinks.sort();
+    sinks.dedup();
+    sinks.iter().for_each(|sink| graph.node(sink, "doublecircle"));
+    Ok(graph)
+}
+
+// The network of the puzzle: the broadcaster starts binary counters made of chains of flip-flops.
+// The conjunction of every counter hears the flip-flops of the 1 bits of its (odd) period, resets
+// the 0 bits and the first one when the period is reached, and pulses through an inverter into the
+// final conjunction in front of rx.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .filter(|name| name != "rx")
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut names);
+    let mut names = names.into_iter();
+
+    let num_bits = size.clamp(2, 5);
+    let output = names.next().unwrap();
+    let mut lines = vec![];
+    let mut starts = vec![];
+    for _ in 0..(size / 2).clamp(1, 4) {
+        let period = (1 << (num_bits - 1)) + 2 * rng.below(1 << (num_bits - 2)) + 1;
+        let flipflops = (0..num_bits).map(|_| names.next().unwrap()).collect::<Vec<String>>();
+        let conjunction = names.next().unwrap();
+        let inverter = names.next().unwrap();
+
+        let mut resets = vec![];
+        for (bit, flipflop) in flipflops.iter().enumerate() {
+            let mut conn = flipflops.get(bit + 1).into_iter().cloned().collect::<Vec<String>>();
+            if period & (1 << bit) != 0 {
+                conn.push(conjunction.clone());
+            }
+            if period & (1 << bit) == 0 || bit == 0 {
+                resets.push(flipflop.clone());
+            }
+            lines.push(format!("%{} -> {}", flipflop, conn.join(", ")));
+        }
+        resets.push(inverter.clone());
+        lines.push(format!("&{} -> {}", conjunction, resets.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, output));
+        starts.push(flipflops[0].clone());
+    }
+    lines.push(format!("&{} -> rx", output));
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::fuzz;
 
     #[test]
     fn simple_case() {
@@ -277,12 +348,12 @@ mod tests {
 %a -> b
 %b -> c
 %c -> inv
-&inv -> a".split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        let p1 = solve_part_1(input.clone());
-        let p2 = solve_part_2(input);
+&inv -> a";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
         assert_eq!(p1, 32000000);
+        // no rx module, the presses stop when the modules are back to their first state
+        assert_eq!(p2, 1);
     }
 
     #[test]
@@ -291,11 +362,33 @@ mod tests {
 %a -> inv, con
 &inv -> b
 %b -> con
-&con -> output".split('\n')
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        let p1 = solve_part_1(input.clone());
-        let p2 = solve_part_2(input);
+&con -> output";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
         assert_eq!(p1, 11687500);
+        assert_eq!(p2, 4);
+    }
+
+    #[test]
+    fn dot_graph() {
+        let input = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+        let graph = dot(input).unwrap().to_string();
+        assert!(graph.contains("\"button\" -> \"broadcaster\";"));
+        assert!(graph.contains("\"a\" [shape=box];"));
+        assert!(graph.contains("\"con\" [shape=invtriangle];"));
+        assert!(graph.contains("\"output\" [shape=doublecircle];"));
+        assert!(graph.contains("\"a\" -> \"inv\";"));
+        assert_eq!(graph.matches(" -> ").count(), 7);
+    }
+
+    #[test]
+    fn fuzz_parser() {
+        fuzz::fuzz(&["broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a",
+                     "broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output"],
+                   |input| { let _ = parse_modules(input); });
     }
 }
@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+
+use crate::utils::{assumption::Assumption, config, image::{self, Image}, rng::Rng, parse::{self, ParseError}, visualize::{self, Cell, Color, Render}};
+
+// Steps of the elf in part 1, `[2023.day21] steps` in aoc.toml
+pub(crate) const STEPS: usize = 64;
+
+pub(crate) fn steps() -> usize {
+    config::param(2023, 21, "steps", |v| usize::try_from(v.as_int()?).ok(), STEPS)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum LocType {
+    Plots,
+    Rock,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Loc {
+    x: i32,
+    y: i32,
+}
+
+// The garden with the plots reached after a number of steps
+struct Garden<'a> {
+    map: &'a [Vec<LocType>],
+    reached: HashSet<(i32, i32)>,
+}
+
+impl Render for Garden<'_> {
+    fn width(&self) -> usize {
+        self.map[0].len()
+    }
+
+    fn height(&self) -> usize {
+        self.map.len()
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        if self.reached.contains(&(x as i32, y as i32)) {
+            Cell::colored('O', Color::Green)
+        } else {
+            match self.map[y][x] {
+                LocType::Plots => Cell::plain('.'),
+                LocType::Rock => Cell::colored('#', Color::Gray),
+            }
+        }
+    }
+}
+
+fn parse_map(input: &str) -> Result<(Vec<Vec<LocType>>, Loc), ParseError> {
+    let mut starts = vec![];
+    let map = parse::grid(input, |x, y, c| {
+        match c {
+            '#' => Some(LocType::Rock),
+            '.' => Some(LocType::Plots),
+            'S' => {
+                starts.push(Loc {x: x as i32, y: y as i32});
+                Some(LocType::Plots)
+            },
+            _ => None,
+        }
+    })?;
+    match starts.pop() {
+        Some(start_loc) if starts.is_empty() => Ok((map, start_loc)),
+        _ => Err(ParseError { line: 1, column: 1, message: "expected exactly one starting plot `S`".to_string() }),
+    }
+}
+
+fn valid_loc(loc: &Loc, map: &[Vec<LocType>]) -> bool {
+    loc.x >= 0 && loc.x < map[0].len() as i32 &&
+    loc.y >= 0 && loc.y < map.len() as i32 &&
+    map[loc.y as usize][loc.x as usize] == LocType::Plots
+}
+
+// The plots where the walk can end after exactly `num_steps` steps
+fn reachable(map: &[Vec<LocType>], start_loc: Loc, num_steps: usize) -> Vec<Loc> {
+    let mut queue = vec![start_loc];
+
+    for step in 0..num_steps {
+        let mut loc_queue = vec![];
+        for _ in 0..queue.len() {
+            let current_loc = queue.remove(0);
+
+            let mut moves = vec![Loc {y: current_loc.y + 1, x: current_loc.x}, 
+                            Loc {y: current_loc.y, x: current_loc.x - 1}, Loc {y: current_loc.y, x: current_loc.x + 1},
+                            Loc {y: current_loc.y - 1, x: current_loc.x}];
+
+            for _ in 0..4 {
+                let mov = moves.remove(0);
+                if valid_loc(&mov, map) && !loc_queue.contains(&mov) {
+                    loc_queue.push(mov);
+                }
+            }
+        }
+        queue = loc_queue;
+
+        if visualize::enabled() {
+            let reached = queue.iter().map(|l| (l.x, l.y)).collect();
+            visualize::frame(&format!("step {}", step + 1), &Garden { map, reached });
+        }
+    }
+    visualize::finish();
+    queue
+}
+
+pub(crate) fn solve_part_1(input: &str, num_steps: usize) -> Result<usize, ParseError> {
+    let (map, start_loc) = parse_map(input)?;
+    Ok(reachable(&map, start_loc, num_steps).len())
+}
+
+// The plots reachable in the steps of part 1
+pub fn export(input: &str) -> Result<Vec<(&'static str, Image)>, ParseError> {
+    let (map, start_loc) = parse_map(input)?;
+    let start = (start_loc.x as usize, start_loc.y as usize);
+    let mut image = Image::from_grid(&map, |_, _, l| if *l == LocType::Rock { image::GRAY } else { image::BLACK });
+    reachable(&map, start_loc, steps()).iter().for_each(|l| image.set(l.x as usize, l.y as usize, image::GREEN));
+    image.set(start.0, start.1, image::RED);
+    Ok(vec![("reachable", image)])
+}
+
+// Part 2 (the infinite garden) needs the reachable plots to grow in the same way in every copy of
+// the map, which holds when S is in the middle of an odd square and the way out of it is clear
+pub fn validate(input: &str) -> Result<Vec<Assumption>, ParseError> {
+    let (map, start) = parse_map(input)?;
+    let (x, y) = (start.x as usize, start.y as usize);
+    let size = map.len();
+    Ok(vec![
+        Assumption::new(format!("the garden is an odd square ({}x{})", map[0].len(), size), map[0].len() == size && size % 2 == 1),
+        Assumption::new(format!("S is in the center (at {}, {})", x, y), x == map[0].len() / 2 && y == size / 2),
+        Assumption::new("the row through S has no rocks", map[y].iter().all(|l| *l == LocType::Plots)),
+        Assumption::new("the column through S has no rocks", map.iter().all(|row| row[x] == LocType::Plots)),
+        Assumption::new("the border has no rocks", map.iter().enumerate().all(|(y, row)| {
+            row.iter().enumerate().all(|(x, l)| *l == LocType::Plots || (0 < x && x + 1 < row.len() && 0 < y && y + 1 < size))
+        })),
+    ])
+}
+
+// Odd square garden with S in the middle of a clear row and column and 10% of rocks
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) / 2 * 2 + 1;
+    let middle = size / 2;
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| match (x == middle, y == middle) {
+                    (true, true) => 'S',
+                    (false, false) if rng.chance(0.1) => '#',
+                    _ => '.',
+                })
+                .collect::<String>() + "\n"
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_case() {
+        let input = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+        let p1 = solve_part_1(input, 6).unwrap();
+        assert_eq!(p1, 16);
+    }
+
+    #[test]
+    fn assumptions() {
+        let input = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+        let holds = validate(input).unwrap().iter().map(|a| a.holds).collect::<Vec<bool>>();
+        assert_eq!(holds, vec![true, true, false, false, true]);
+
+        let mut rng = Rng::new(3);
+        let input = generate(&mut rng, 9);
+        assert!(validate(&input).unwrap()[..4].iter().all(|a| a.holds));
+    }
+}
@@ -0,0 +1,51 @@
+// Starting point of a new day: copy it to dayNN.rs, declare `pub mod dayNN;` and register the day in
+// `parts()`, `generator()` and `TITLES` of this year's mod.rs (`validator()`, `grapher()` and
+// `exporter()` when the day has them). The runner reads, normalizes and times the input, the parts
+// only parse and solve it.
+
+use crate::utils::{rng::Rng, parse::{self, ParseError}};
+
+fn parse_numbers(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse::lines(input)
+        .map(|line| line.numbers::<i64>())
+        .collect()
+}
+
+pub(crate) fn solve_part_1(input: &str) -> Result<i64, ParseError> {
+    Ok(parse_numbers(input)?.iter()
+        .map(|numbers| numbers.iter().sum::<i64>())
+        .sum())
+}
+
+pub(crate) fn solve_part_2(input: &str) -> Result<i64, ParseError> {
+    Ok(parse_numbers(input)?.iter()
+        .map(|numbers| numbers.iter().product::<i64>())
+        .sum())
+}
+
+// `size` lines of a few small numbers
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..1 + rng.below(5))
+                .map(|_| rng.range(-9..10).to_string())
+                .collect::<Vec<String>>()
+                .join(" ") + "\n"
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_case() {
+        let input = "1 2 3\n4 5";
+        let p1 = solve_part_1(input).unwrap();
+        let p2 = solve_part_2(input).unwrap();
+        assert_eq!(p1, 15);
+        assert_eq!(p2, 26);
+    }
+}
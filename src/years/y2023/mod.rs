@@ -0,0 +1,265 @@
+// only built by the tests, which keep it compiling against the current interface
+#[cfg(test)]
+pub mod day_template;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+
+use super::{Exporter, Generator, Grapher, Part, Validator};
+use crate::utils::config::Kind;
+
+pub struct Calendar;
+
+impl super::Calendar for Calendar {
+    fn title(&self, day: u8) -> Option<&'static str> {
+        title(day)
+    }
+
+    fn parts(&self, day: u8) -> Vec<Part> {
+        parts(day)
+    }
+
+    fn generator(&self, day: u8) -> Option<Generator> {
+        generator(day)
+    }
+
+    fn validator(&self, day: u8) -> Option<Validator> {
+        validator(day)
+    }
+
+    fn grapher(&self, day: u8) -> Option<Grapher> {
+        grapher(day)
+    }
+
+    fn exporter(&self, day: u8) -> Option<Exporter> {
+        exporter(day)
+    }
+
+    fn params(&self, day: u8) -> &'static [(&'static str, Kind)] {
+        params(day)
+    }
+}
+
+const TITLES: [&str; 25] = [
+    "Trebuchet?!", "Cube Conundrum", "Gear Ratios", "Scratchcards", "If You Give A Seed A Fertilizer",
+    "Wait For It", "Camel Cards", "Haunted Wasteland", "Mirage Maintenance", "Pipe Maze",
+    "Cosmic Expansion", "Hot Springs", "Point of Incidence", "Parabolic Reflector Dish", "Lens Library",
+    "The Floor Will Be Lava", "Clumsy Crucible", "Lavaduct Lagoon", "Aplenty", "Pulse Propagation",
+    "Step Counter", "Sand Slabs", "A Long Walk", "Never Tell Me The Odds", "Snowverload",
+];
+
+pub fn title(day: u8) -> Option<&'static str> {
+    TITLES.get((day as usize).checked_sub(1)?).copied()
+}
+
+// Random input of roughly `size` lines (or cells per side) for the day
+pub fn generator(day: u8) -> Option<Generator> {
+    match day {
+        1 => Some(day01::generate),
+        2 => Some(day02::generate),
+        3 => Some(day03::generate),
+        4 => Some(day04::generate),
+        5 => Some(day05::generate),
+        6 => Some(day06::generate),
+        7 => Some(day07::generate),
+        8 => Some(day08::generate),
+        9 => Some(day09::generate),
+        10 => Some(day10::generate),
+        11 => Some(day11::generate),
+        12 => Some(day12::generate),
+        13 => Some(day13::generate),
+        14 => Some(day14::generate),
+        15 => Some(day15::generate),
+        16 => Some(day16::generate),
+        17 => Some(day17::generate),
+        18 => Some(day18::generate),
+        19 => Some(day19::generate),
+        20 => Some(day20::generate),
+        21 => Some(day21::generate),
+        _ => None,
+    }
+}
+
+
+// Checks of the assumptions the shortcuts of the day take about the input
+pub fn validator(day: u8) -> Option<Validator> {
+    match day {
+        8 => Some(day08::validate),
+        10 => Some(day10::validate),
+        14 => Some(day14::validate),
+        21 => Some(day21::validate),
+        _ => None,
+    }
+}
+
+// Named images of the final states of the grid puzzles
+pub fn exporter(day: u8) -> Option<Exporter> {
+    match day {
+        10 => Some(day10::export),
+        14 => Some(day14::export),
+        16 => Some(day16::export),
+        17 => Some(day17::export),
+        18 => Some(day18::export),
+        21 => Some(day21::export),
+        _ => None,
+    }
+}
+
+// DOT graphs of the parsed structure of the graph-shaped puzzles
+pub fn grapher(day: u8) -> Option<Grapher> {
+    match day {
+        8 => Some(day08::dot),
+        19 => Some(day19::dot),
+        20 => Some(day20::dot),
+        _ => None,
+    }
+}
+
+// The parameters of the puzzles that `[2023.dayNN]` of aoc.toml can change
+pub fn params(day: u8) -> &'static [(&'static str, Kind)] {
+    match day {
+        2 => &[("cubes", Kind::Counts(3))],
+        11 => &[("expansion", Kind::Counts(2))],
+        21 => &[("steps", Kind::Count)],
+        _ => &[],
+    }
+}
+
+fn first_line(input: &str) -> &str {
+    input.lines().next().unwrap_or("")
+}
+
+// The implemented parts of the day with the parameters of the puzzle, the answers as text
+pub fn parts(day: u8) -> Vec<Part> {
+    match day {
+        1 => vec![
+            |input| Ok(day01::solve_part_1(input).to_string()),
+            |input| Ok(day01::solve_part_2(input).to_string()),
+        ],
+        2 => vec![
+            |input| Ok(day02::solve_part_1(input, day02::cubes_limit())?.to_string()),
+            |input| Ok(day02::solve_part_2(input)?.to_string()),
+        ],
+        3 => vec![
+            |input| Ok(day03::solve_part_1(input).to_string()),
+            |input| Ok(day03::solve_part_2(input).to_string()),
+        ],
+        4 => vec![
+            |input| Ok(day04::solve_part_1(input)?.to_string()),
+            |input| Ok(day04::solve_part_2(input)?.to_string()),
+        ],
+        5 => vec![
+            |input| Ok(day05::solve_part_1(input)?.to_string()),
+            |input| Ok(day05::solve_part_2(input)?.to_string()),
+        ],
+        6 => vec![
+            |input| Ok(day06::solve_part_1(input)?.to_string()),
+            |input| Ok(day06::solve_part_2(input)?.to_string()),
+        ],
+        7 => vec![
+            |input| Ok(day07::solve_part_1(input)?.to_string()),
+            |input| Ok(day07::solve_part_2(input)?.to_string()),
+        ],
+        8 => vec![
+            |input| Ok(day08::solve_part_1(input)?.to_string()),
+            |input| Ok(day08::solve_part_2(input)?.to_string()),
+        ],
+        9 => vec![
+            |input| Ok(day09::solve_part_1(input)?.to_string()),
+            |input| Ok(day09::solve_part_2(input)?.to_string()),
+        ],
+        10 => vec![
+            |input| Ok(day10::solve_part_1(input)?.to_string()),
+            |input| Ok(day10::solve_part_2(input)?.to_string()),
+        ],
+        11 => vec![
+            |input| Ok(day11::solve_part_2(input, day11::expansion()[0])?.to_string()),
+            |input| Ok(day11::solve_part_2(input, day11::expansion()[1])?.to_string()),
+        ],
+        12 => vec![
+            |input| Ok(day12::solve_part_1(input)?.to_string()),
+            |input| Ok(day12::solve_part_2(input)?.to_string()),
+        ],
+        13 => vec![
+            |input| Ok(day13::solve_part_1(input)?.to_string()),
+            |input| Ok(day13::solve_part_2(input)?.to_string()),
+        ],
+        14 => vec![
+            |input| Ok(day14::solve_part_1(input)?.to_string()),
+            |input| Ok(day14::solve_part_2(input)?.to_string()),
+        ],
+        15 => vec![
+            |input| Ok(day15::solve_part_1(first_line(input)).to_string()),
+            |input| Ok(day15::solve_part_2(first_line(input))?.to_string()),
+        ],
+        16 => vec![
+            |input| Ok(day16::solve_part_1(input)?.to_string()),
+            |input| Ok(day16::solve_part_2(input)?.to_string()),
+        ],
+        17 => vec![
+            |input| Ok(day17::solve_part_1(input)?.to_string()),
+            |input| Ok(day17::solve_part_2(input)?.to_string()),
+        ],
+        18 => vec![
+            |input| Ok(day18::solve_part_1(input)?.to_string()),
+            |input| Ok(day18::solve_part_2(input)?.to_string()),
+        ],
+        19 => vec![
+            |input| Ok(day19::solve_part_1(input)?.to_string()),
+            |input| Ok(day19::solve_part_2(input)?.to_string()),
+        ],
+        20 => vec![
+            |input| Ok(day20::solve_part_1(input)?.to_string()),
+            |input| Ok(day20::solve_part_2(input)?.to_string()),
+        ],
+        21 => vec![
+            |input| Ok(day21::solve_part_1(input, day21::steps())?.to_string()),
+        ],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{utils::rng::Rng, years};
+
+    #[test]
+    fn generators_are_deterministic() {
+        for day in 1..=25 {
+            assert_eq!(!parts(day).is_empty(), generator(day).is_some());
+            if let Some(generate) = generator(day) {
+                let input = generate(&mut Rng::new(day as u64), 6);
+                assert!(!input.trim().is_empty());
+                assert_eq!(input, generate(&mut Rng::new(day as u64), 6));
+            }
+        }
+    }
+
+    #[test]
+    fn configurable_params() {
+        assert_eq!(years::param_kind("2023.day11.expansion"), Some(Kind::Counts(2)));
+        assert_eq!(years::param_kind("2023.day21.steps"), Some(Kind::Count));
+        assert_eq!(years::param_kind("2023.day2.cubes"), None);
+        assert_eq!(years::param_kind("2023.day05.seeds"), None);
+        assert_eq!(years::param_kind("2015.day02.cubes"), None);
+    }
+}